@@ -34,6 +34,22 @@ impl fmt::Display for AtomStr {
     }
 }
 
+#[cfg(feature = "serialize")]
+impl serde::Serialize for AtomStr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "cache")]
+impl<'de> serde::Deserialize<'de> for AtomStr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+
+        Ok(AtomStr::from(&value))
+    }
+}
+
 impl fmt::Debug for AtomStr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.as_str().fmt(f)
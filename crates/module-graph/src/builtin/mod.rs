@@ -0,0 +1,58 @@
+use crate::module::*;
+use crate::module_graph_error::ModuleGraphError;
+use nodejs_package_json::PackageJson;
+use oxc_resolver::NODEJS_BUILTINS;
+use std::sync::Arc;
+
+/// Builtin modules that can only be imported with the `node:` prefix.
+const NODEJS_PREFIXED_BUILTINS: &[&str] = &["sea", "sqlite", "test", "test/reporters"];
+
+/// A module provided by the Node.js runtime, like `node:fs`.
+/// The module path is the specifier with the `node:` prefix.
+#[derive(Debug)]
+pub struct BuiltinModule {
+    pub name: String,
+}
+
+impl BuiltinModule {
+    /// Return the builtin name, without the `node:` prefix, if the
+    /// specifier refers to a Node.js builtin module.
+    pub fn parse_specifier(specifier: &str) -> Option<&str> {
+        if let Some(name) = specifier.strip_prefix("node:") {
+            return (NODEJS_BUILTINS.binary_search(&name).is_ok()
+                || NODEJS_PREFIXED_BUILTINS.contains(&name))
+            .then_some(name);
+        }
+
+        NODEJS_BUILTINS
+            .binary_search(&specifier)
+            .is_ok()
+            .then_some(specifier)
+    }
+}
+
+impl ModuleSource for BuiltinModule {
+    fn kind(&self) -> SourceKind {
+        SourceKind::Builtin
+    }
+
+    fn source(&self) -> &[u8] {
+        &[]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn metadata(&self) -> starbase_utils::json::JsonValue {
+        starbase_utils::json::json!({ "name": self.name })
+    }
+
+    fn load(
+        module: &mut Module,
+        _package_json: Option<Arc<PackageJson>>,
+    ) -> Result<Self, ModuleGraphError> {
+        let path = module.path.to_string_lossy();
+
+        Ok(BuiltinModule {
+            name: path.strip_prefix("node:").unwrap_or(&path).to_owned(),
+        })
+    }
+}
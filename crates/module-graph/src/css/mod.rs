@@ -13,21 +13,51 @@ use starbase_utils::fs;
 use std::collections::BTreeMap;
 use std::fmt;
 use std::mem;
-use std::sync::Arc;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
 
 pub use css_error::CssModuleError;
 
 pub struct CssModule {
     pub exports: FxIndexMap<String, String>,
     pub module: bool,
-    pub sheet: StyleSheet<'static, 'static>,
+    pub path: PathBuf,
     pub source: Arc<String>,
+    sheet: OnceLock<StyleSheet<'static, 'static>>,
 }
 
 impl CssModule {
     pub fn is_css_module(&self) -> bool {
         self.module
     }
+
+    /// Return the parsed stylesheet. It's parsed on first access, so that
+    /// modules restored from the cache are never parsed.
+    pub fn get_sheet(&self) -> Result<&StyleSheet<'static, 'static>, ModuleGraphError> {
+        if let Some(sheet) = self.sheet.get() {
+            return Ok(sheet);
+        }
+
+        let sheet = StyleSheet::parse(
+            &self.source,
+            ParserOptions {
+                filename: fs::file_name(&self.path),
+                css_modules: self.module.then(Config::default),
+                ..ParserOptions::default()
+            },
+        )
+        .map_err(|error| {
+            Box::new(CssModuleError::ParseFailed {
+                path: self.path.to_owned(),
+                error: Box::new(error.into_owned()),
+            })
+        })?;
+
+        // The sheet borrows from the source, which is never mutated
+        Ok(self.sheet.get_or_init(|| unsafe {
+            mem::transmute::<StyleSheet<'_, '_>, StyleSheet<'static, 'static>>(sheet)
+        }))
+    }
 }
 
 impl ModuleSource for CssModule {
@@ -39,54 +69,62 @@ impl ModuleSource for CssModule {
         self.source.as_bytes()
     }
 
+    #[cfg(feature = "serialize")]
+    fn metadata(&self) -> starbase_utils::json::JsonValue {
+        starbase_utils::json::json!({
+            "cssModule": self.module,
+            "exports": self.exports.iter().collect::<std::collections::BTreeMap<_, _>>(),
+        })
+    }
+
+    #[cfg(feature = "cache")]
+    fn get_cache_state(&self) -> Option<starbase_utils::json::JsonValue> {
+        // Pairs to preserve the order of exports
+        Some(starbase_utils::json::json!({
+            "exports": self.exports.iter().collect::<Vec<_>>(),
+        }))
+    }
+
+    #[cfg(feature = "cache")]
+    fn set_cache_state(&mut self, mut state: starbase_utils::json::JsonValue) -> bool {
+        match starbase_utils::json::serde_json::from_value::<Vec<(String, String)>>(
+            state["exports"].take(),
+        ) {
+            Ok(exports) => {
+                self.exports = FxIndexMap::from_iter(exports);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
     fn load(
         module: &mut Module,
         _package_json: Option<Arc<PackageJson>>,
     ) -> Result<Self, ModuleGraphError> {
-        let file_name = fs::file_name(&module.path);
-        let css_module = file_name.ends_with(".module.css");
-        let source = Arc::new(fs::read_file(&module.path)?);
-
-        let sheet = StyleSheet::parse(
-            &source,
-            ParserOptions {
-                filename: file_name,
-                css_modules: css_module.then(Config::default),
-                ..ParserOptions::default()
-            },
-        )
-        .map_err(|error| {
-            Box::new(CssModuleError::ParseFailed {
-                path: module.path.to_owned(),
-                error: Box::new(error.into_owned()),
-            })
-        })?;
-
         Ok(CssModule {
             exports: FxIndexMap::default(),
-            module: css_module,
-            sheet: unsafe {
-                mem::transmute::<StyleSheet<'_, '_>, StyleSheet<'static, 'static>>(sheet)
-            },
-            source,
+            module: fs::file_name(&module.path).ends_with(".module.css"),
+            path: module.path.clone(),
+            source: Arc::new(fs::read_file(&module.path)?),
+            sheet: OnceLock::new(),
         })
     }
 
     fn parse(&mut self, module: &mut Module) -> Result<(), ModuleGraphError> {
+        let sheet = self.get_sheet()?;
+
         if !self.is_css_module() {
             return Ok(());
         }
 
         let mut exports_hashes = BTreeMap::default();
-        let css = self
-            .sheet
-            .to_css(PrinterOptions::default())
-            .map_err(|error| {
-                Box::new(CssModuleError::ParseModuleFailed {
-                    path: module.path.to_owned(),
-                    error: Box::new(error),
-                })
-            })?;
+        let css = sheet.to_css(PrinterOptions::default()).map_err(|error| {
+            Box::new(CssModuleError::ParseModuleFailed {
+                path: module.path.to_owned(),
+                error: Box::new(error),
+            })
+        })?;
 
         let mut map_module_import = |imports: Vec<CssModuleReference>| {
             for import in imports {
@@ -109,7 +147,10 @@ impl ModuleSource for CssModule {
 
                     module.imports.push(Import {
                         kind: ImportKind::SyncStatic,
+                        attributes: None,
+                        glob: None,
                         module_id: 0,
+                        pattern: None,
                         source_request: AtomStr::from(specifier.as_str()),
                         span: Span::default(),
                         symbols: vec![symbol],
@@ -131,6 +172,7 @@ impl ModuleSource for CssModule {
                     kind: ExportKind::Native,
                     symbols: vec![ExportedSymbol {
                         kind: ExportedKind::Value,
+                        exported_name: None,
                         symbol_id: None,
                         name: AtomStr::from(source_name.as_str()),
                     }],
@@ -0,0 +1,52 @@
+use crate::module::*;
+use crate::module_graph_error::ModuleGraphError;
+use nodejs_package_json::PackageJson;
+use std::sync::Arc;
+
+/// A third-party package that the graph does not traverse into.
+/// The module path is the root directory of the package.
+#[derive(Debug)]
+pub struct ExternalModule {
+    pub package_name: String,
+    pub version: Option<String>,
+}
+
+impl ModuleSource for ExternalModule {
+    fn kind(&self) -> SourceKind {
+        SourceKind::External
+    }
+
+    fn source(&self) -> &[u8] {
+        &[]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn metadata(&self) -> starbase_utils::json::JsonValue {
+        starbase_utils::json::json!({
+            "packageName": self.package_name,
+            "version": self.version,
+        })
+    }
+
+    fn load(
+        module: &mut Module,
+        package_json: Option<Arc<PackageJson>>,
+    ) -> Result<Self, ModuleGraphError> {
+        let package_json = package_json.as_deref();
+
+        Ok(ExternalModule {
+            package_name: package_json
+                .and_then(|package| package.name.clone())
+                .unwrap_or_else(|| {
+                    module
+                        .path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or_default()
+                }),
+            version: package_json
+                .and_then(|package| package.version.as_ref())
+                .map(|version| version.to_string()),
+        })
+    }
+}
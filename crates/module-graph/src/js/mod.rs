@@ -42,13 +42,43 @@ impl JavaScriptModule {
 
 impl ModuleSource for JavaScriptModule {
     fn kind(&self) -> SourceKind {
-        SourceKind::Unknown
+        SourceKind::JavaScript
     }
 
     fn source(&self) -> &[u8] {
         self.source.as_bytes()
     }
 
+    #[cfg(feature = "serialize")]
+    fn metadata(&self) -> starbase_utils::json::JsonValue {
+        starbase_utils::json::json!({
+            "jsx": self.source_type.is_jsx(),
+            "packageType": self.package_type,
+            "stats": self.stats,
+            "typescript": self.source_type.is_typescript(),
+        })
+    }
+
+    #[cfg(feature = "cache")]
+    fn get_cache_state(&self) -> Option<JsonValue> {
+        Some(starbase_utils::json::json!({ "stats": self.stats }))
+    }
+
+    #[cfg(feature = "cache")]
+    fn set_cache_state(&mut self, mut state: JsonValue) -> bool {
+        match starbase_utils::json::serde_json::from_value(state["stats"].take()) {
+            Ok(stats) => {
+                self.stats = stats;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    fn as_javascript(&self) -> Option<&JavaScriptModule> {
+        Some(self)
+    }
+
     fn load(
         module: &mut Module,
         package_json: Option<Arc<PackageJson>>,
@@ -96,12 +126,18 @@ impl ModuleSource for JavaScriptModule {
             .into());
         }
 
+        // Extract reference directives, which precede all imports
+        if self.source_type.is_typescript() {
+            extract_reference_directives(module, &source, &result.trivias, &result.program);
+        }
+
         // Extract imports and exports
         {
             let mut stats = JavaScriptStats::default();
             let mut visitor = ExtractImportsExports {
                 module,
                 stats: &mut stats,
+                extracted_asset_urls: FxHashSet::default(),
                 extracted_dynamic_imports: FxHashSet::default(),
                 extracted_requires: FxHashSet::default(),
                 ast: std::marker::PhantomData,
@@ -116,6 +152,8 @@ impl ModuleSource for JavaScriptModule {
 }
 
 #[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "kebab-case"))]
 pub enum JavaScriptPackageType {
     #[default]
     Unknown,
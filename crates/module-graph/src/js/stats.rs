@@ -1,4 +1,7 @@
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "cache", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub struct JavaScriptStats {
     pub dynamic_import_count: usize,
     pub export_statements: usize,
@@ -2,26 +2,28 @@ use super::stats::JavaScriptStats;
 use crate::atom::*;
 use crate::module::*;
 use oxc::ast::ast::{
-    Argument, AssignmentTarget, BindingPattern, BindingPatternKind, CallExpression, Declaration,
-    ExportAllDeclaration, ExportDefaultDeclaration, ExportDefaultDeclarationKind,
-    ExportNamedDeclaration, Expression, ImportDeclaration, ImportDeclarationSpecifier,
-    ImportExpression, MemberExpression, ModuleDeclaration, SimpleAssignmentTarget, Statement,
+    Argument, ArrayExpressionElement, AssignmentTarget, BindingPattern, BindingPatternKind,
+    CallExpression, Declaration, ExportAllDeclaration, ExportDefaultDeclaration,
+    ExportDefaultDeclarationKind, ExportNamedDeclaration, Expression, ImportDeclaration,
+    ImportDeclarationSpecifier, ImportExpression, MemberExpression, ModuleDeclaration,
+    NewExpression, ObjectPropertyKind, Program, RegExpFlags, SimpleAssignmentTarget, Statement,
     TSModuleReference,
 };
-use oxc::ast::{AstKind, Visit};
-use oxc::span::Span;
+use oxc::ast::{AstKind, Trivias, Visit};
+use oxc::span::{GetSpan, Span};
+use oxc::syntax::operator::BinaryOperator;
 use rustc_hash::FxHashSet;
 use std::marker::PhantomData;
 
 pub struct ExtractImportsExports<'ast, 'module> {
     pub module: &'module mut Module,
     pub stats: &'module mut JavaScriptStats,
+    pub extracted_asset_urls: FxHashSet<Span>,
     pub extracted_dynamic_imports: FxHashSet<Span>,
     pub extracted_requires: FxHashSet<Span>,
     pub ast: PhantomData<&'ast ()>,
 }
 
-// TODO non-literal paths
 impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
     fn enter_node(&mut self, kind: AstKind<'ast>) {
         match kind {
@@ -73,6 +75,7 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
                             span: Some(expr.span),
                             symbols: vec![ExportedSymbol {
                                 kind: ExportedKind::Default,
+                                exported_name: None,
                                 symbol_id: None,
                                 name: name
                                     .map(|n| n.to_atom_str())
@@ -88,19 +91,24 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
             }
 
             // require()
-            AstKind::CallExpression(require) => {
-                if require.callee.is_specific_id("require") && require.arguments.len() == 1 {
-                    if let Argument::Expression(Expression::StringLiteral(source)) =
-                        &require.arguments[0]
+            // import.meta.glob()
+            // require.context()
+            AstKind::CallExpression(call) => {
+                if call.callee.is_specific_id("require") && call.arguments.len() == 1 {
+                    if let Some((source_request, pattern)) =
+                        extract_specifier_from_argument(&call.arguments[0])
                     {
-                        if !self.extracted_requires.contains(&require.span) {
-                            self.extracted_requires.insert(require.span);
+                        if !self.extracted_requires.contains(&call.span) {
+                            self.extracted_requires.insert(call.span);
 
                             self.module.imports.push(Import {
                                 kind: ImportKind::SyncStatic,
+                                attributes: None,
+                                glob: None,
                                 module_id: 0,
-                                source_request: source.value.to_atom_str(),
-                                span: require.span,
+                                pattern,
+                                source_request,
+                                span: call.span,
                                 type_only: false,
                                 symbols: vec![],
                             });
@@ -108,6 +116,50 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
                         }
                     };
                 }
+
+                if let Some(record) = extract_glob_import(call) {
+                    self.module.imports.push(record);
+                }
+            }
+
+            // new URL('./file', import.meta.url)
+            // new Worker(new URL('./file', import.meta.url))
+            AstKind::NewExpression(expr) => {
+                let mut kind = ImportKind::AssetUrl;
+                let mut url = expr;
+
+                if expr.callee.is_specific_id("Worker")
+                    || expr.callee.is_specific_id("SharedWorker")
+                {
+                    let Some(Argument::Expression(Expression::NewExpression(inner))) =
+                        expr.arguments.first()
+                    else {
+                        return;
+                    };
+
+                    kind = ImportKind::Worker;
+                    url = inner;
+                }
+
+                let Some((source_request, pattern)) = extract_asset_url(url) else {
+                    return;
+                };
+
+                if !self.extracted_asset_urls.contains(&url.span) {
+                    self.extracted_asset_urls.insert(url.span);
+
+                    self.module.imports.push(Import {
+                        kind,
+                        attributes: None,
+                        glob: None,
+                        module_id: 0,
+                        pattern,
+                        source_request,
+                        span: expr.span,
+                        type_only: false,
+                        symbols: vec![],
+                    });
+                }
             }
 
             // export = value
@@ -117,6 +169,7 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
                     span: Some(export.span),
                     symbols: vec![ExportedSymbol {
                         kind: ExportedKind::Default,
+                        exported_name: None,
                         symbol_id: None,
                         name: AtomStr::from("default"),
                     }],
@@ -132,6 +185,7 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
                         span: Some(expr.span),
                         symbols: vec![ExportedSymbol {
                             kind: ExportedKind::Value,
+                            exported_name: None,
                             symbol_id: None,
                             name: expr.property.name.to_atom_str(),
                         }],
@@ -147,7 +201,10 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
                 {
                     self.module.imports.push(Import {
                         kind: ImportKind::SyncStatic,
+                        attributes: None,
+                        glob: None,
                         module_id: 0,
+                        pattern: None,
                         source_request: ext_module.expression.value.to_atom_str(),
                         span: decl.span,
                         symbols: vec![ImportedSymbol {
@@ -170,14 +227,17 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
 
                 // import()
                 if let Some(import) = extract_dynamic_import_from_expression(init) {
-                    if let Expression::StringLiteral(source) = &import.source {
+                    if let Some((source_request, pattern)) = extract_specifier(&import.source) {
                         if !self.extracted_dynamic_imports.contains(&import.span) {
                             self.extracted_dynamic_imports.insert(import.span);
 
                             let mut record = Import {
                                 kind: ImportKind::AsyncDynamic,
+                                attributes: extract_dynamic_import_attributes(import),
+                                glob: None,
                                 module_id: 0,
-                                source_request: source.value.to_atom_str(),
+                                pattern,
+                                source_request,
                                 span: import.span,
                                 type_only: false,
                                 symbols: vec![],
@@ -193,16 +253,19 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
 
                 // require()
                 if let Some(require) = extract_require_from_expression(init) {
-                    if let Argument::Expression(Expression::StringLiteral(source)) =
-                        &require.arguments[0]
+                    if let Some((source_request, pattern)) =
+                        extract_specifier_from_argument(&require.arguments[0])
                     {
                         if !self.extracted_requires.contains(&require.span) {
                             self.extracted_requires.insert(require.span);
 
                             let mut record = Import {
                                 kind: ImportKind::SyncStatic,
+                                attributes: None,
+                                glob: None,
                                 module_id: 0,
-                                source_request: source.value.to_atom_str(),
+                                pattern,
+                                source_request,
                                 span: require.span,
                                 type_only: false,
                                 symbols: vec![],
@@ -243,12 +306,14 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
         if let Some(namespace) = &export.exported {
             record.symbols.push(ExportedSymbol {
                 kind,
+                exported_name: None,
                 symbol_id: None,
                 name: namespace.name().to_atom_str(),
             });
         } else {
             record.symbols.push(ExportedSymbol {
                 kind,
+                exported_name: None,
                 symbol_id: None,
                 name: AtomStr::from("*"),
             });
@@ -272,6 +337,7 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
             ExportDefaultDeclarationKind::Expression(Expression::Identifier(ident)) => {
                 record.symbols.push(ExportedSymbol {
                     kind: ExportedKind::Default,
+                    exported_name: None,
                     symbol_id: None,
                     name: ident.name.to_atom_str(),
                 });
@@ -294,12 +360,14 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
                     } else {
                         ExportedKind::Default
                     },
+                    exported_name: None,
                     symbol_id: ident.symbol_id.clone().into_inner(),
                     name: ident.name.to_atom_str(),
                 });
             } else {
                 record.symbols.push(ExportedSymbol {
                     kind: ExportedKind::Default,
+                    exported_name: None,
                     symbol_id: None,
                     name: AtomStr::from("default"),
                 });
@@ -336,6 +404,7 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
 
                     record.symbols.push(ExportedSymbol {
                         kind: ExportedKind::Value,
+                        exported_name: None,
                         symbol_id: id.symbol_id.clone().into_inner(),
                         name: id.name.to_atom_str(),
                     });
@@ -345,6 +414,7 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
 
                     record.symbols.push(ExportedSymbol {
                         kind: ExportedKind::Value,
+                        exported_name: None,
                         symbol_id: id.symbol_id.clone().into_inner(),
                         name: id.name.to_atom_str(),
                     });
@@ -352,6 +422,7 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
                 Declaration::TSTypeAliasDeclaration(d) => {
                     record.symbols.push(ExportedSymbol {
                         kind: ExportedKind::ValueType,
+                        exported_name: None,
                         symbol_id: d.id.symbol_id.clone().into_inner(),
                         name: d.id.name.to_atom_str(),
                     });
@@ -359,6 +430,7 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
                 Declaration::TSInterfaceDeclaration(d) => {
                     record.symbols.push(ExportedSymbol {
                         kind: ExportedKind::ValueType,
+                        exported_name: None,
                         symbol_id: d.id.symbol_id.clone().into_inner(),
                         name: d.id.name.to_atom_str(),
                     });
@@ -366,6 +438,7 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
                 Declaration::TSEnumDeclaration(d) => {
                     record.symbols.push(ExportedSymbol {
                         kind: ExportedKind::ValueType,
+                        exported_name: None,
                         symbol_id: d.id.symbol_id.clone().into_inner(),
                         name: d.id.name.to_atom_str(),
                     });
@@ -373,6 +446,7 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
                 Declaration::TSModuleDeclaration(d) => {
                     record.symbols.push(ExportedSymbol {
                         kind: ExportedKind::ValueType,
+                        exported_name: None,
                         symbol_id: None,
                         name: d.id.name().to_atom_str(),
                     });
@@ -380,6 +454,7 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
                 Declaration::TSImportEqualsDeclaration(d) => {
                     record.symbols.push(ExportedSymbol {
                         kind: ExportedKind::ValueType,
+                        exported_name: None,
                         symbol_id: d.id.symbol_id.clone().into_inner(),
                         name: d.id.name.to_atom_str(),
                     });
@@ -389,14 +464,23 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
         }
 
         for specifier in &export.specifiers {
+            let local_name = specifier.local.name();
+            let exported_name = specifier.exported.name();
+
             record.symbols.push(ExportedSymbol {
                 kind: if export.export_kind.is_type() || specifier.export_kind.is_type() {
                     ExportedKind::ValueType
                 } else {
                     ExportedKind::Value
                 },
+                // export { local as exported }
+                exported_name: if local_name == exported_name {
+                    None
+                } else {
+                    Some(exported_name.to_atom_str())
+                },
                 symbol_id: None, // Is this correct?
-                name: specifier.local.name().to_atom_str(),
+                name: local_name.to_atom_str(),
             });
         }
 
@@ -419,7 +503,22 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
     fn visit_import_declaration(&mut self, import: &ImportDeclaration<'ast>) {
         let mut record = Import {
             kind: ImportKind::AsyncStatic,
+            attributes: import.with_clause.as_ref().map(|clause| ImportAttributes {
+                assert: clause.attributes_keyword.name == "assert",
+                entries: clause
+                    .with_entries
+                    .iter()
+                    .map(|attr| {
+                        (
+                            attr.key.as_atom().to_atom_str(),
+                            attr.value.value.to_atom_str(),
+                        )
+                    })
+                    .collect(),
+            }),
+            glob: None,
             module_id: 0,
+            pattern: None,
             source_request: import.source.value.to_atom_str(),
             span: import.span,
             type_only: import.import_kind.is_type(),
@@ -480,14 +579,17 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
 
     // import()
     fn visit_import_expression(&mut self, import: &ImportExpression<'ast>) {
-        if let Expression::StringLiteral(source) = &import.source {
+        if let Some((source_request, pattern)) = extract_specifier(&import.source) {
             if !self.extracted_dynamic_imports.contains(&import.span) {
                 self.extracted_dynamic_imports.insert(import.span);
 
                 self.module.imports.push(Import {
                     kind: ImportKind::AsyncDynamic,
+                    attributes: extract_dynamic_import_attributes(import),
+                    glob: None,
                     module_id: 0,
-                    source_request: source.value.to_atom_str(),
+                    pattern,
+                    source_request,
                     span: import.span,
                     type_only: false,
                     symbols: vec![],
@@ -498,6 +600,399 @@ impl<'ast, 'module> Visit<'ast> for ExtractImportsExports<'ast, 'module> {
     }
 }
 
+/// Return the specifier of a string or template literal. Non-literal specifiers,
+/// like template literals with expressions or string concatenation, are returned
+/// as a glob-like pattern, where each dynamic part is replaced with `*`. Patterns
+/// without a static prefix, like `import(name)`, can't be resolved.
+fn extract_specifier(expr: &Expression) -> Option<(AtomStr, Option<AtomStr>)> {
+    let mut specifier = String::new();
+
+    if collect_specifier_parts(expr, &mut specifier) {
+        return Some((AtomStr::from(&specifier), None));
+    }
+
+    // Consecutive dynamic parts, like `a + b`, match the same as one
+    let mut pattern = String::with_capacity(specifier.len());
+
+    for ch in specifier.chars() {
+        if ch != '*' || !pattern.ends_with('*') {
+            pattern.push(ch);
+        }
+    }
+
+    if pattern.starts_with('*') {
+        return None;
+    }
+
+    let pattern = AtomStr::from(&pattern);
+
+    Some((pattern.clone(), Some(pattern)))
+}
+
+fn extract_specifier_from_argument(arg: &Argument) -> Option<(AtomStr, Option<AtomStr>)> {
+    match arg {
+        Argument::Expression(expr) => extract_specifier(expr),
+        _ => None,
+    }
+}
+
+/// Append the static parts of the expression to the specifier, and `*` for
+/// each dynamic part. Return true if the expression is entirely static.
+fn collect_specifier_parts(expr: &Expression, specifier: &mut String) -> bool {
+    match expr {
+        // './file'
+        Expression::StringLiteral(lit) => {
+            specifier.push_str(lit.value.as_str());
+            true
+        }
+
+        // `./dir/${name}.js`
+        Expression::TemplateLiteral(tpl) => {
+            for (index, quasi) in tpl.quasis.iter().enumerate() {
+                if index > 0 {
+                    specifier.push('*');
+                }
+
+                specifier.push_str(
+                    quasi
+                        .value
+                        .cooked
+                        .as_ref()
+                        .unwrap_or(&quasi.value.raw)
+                        .as_str(),
+                );
+            }
+
+            tpl.expressions.is_empty()
+        }
+
+        // './dir/' + name + '.js'
+        Expression::BinaryExpression(bin) if bin.operator == BinaryOperator::Addition => {
+            let left = collect_specifier_parts(&bin.left, specifier);
+            let right = collect_specifier_parts(&bin.right, specifier);
+
+            left && right
+        }
+
+        Expression::ParenthesizedExpression(paren) => {
+            collect_specifier_parts(&paren.expression, specifier)
+        }
+
+        _ => {
+            specifier.push('*');
+            false
+        }
+    }
+}
+
+/// Extract an import of many files from `import.meta.glob(patterns, { eager, import })`
+/// or `require.context(directory, recursive, regex, mode)`. Like non-literal
+/// specifiers, the specifier is a glob-like pattern until it's expanded.
+fn extract_glob_import(call: &CallExpression) -> Option<Import> {
+    let Expression::MemberExpression(callee) = &call.callee else {
+        return None;
+    };
+
+    let mut record = Import {
+        attributes: None,
+        glob: None,
+        kind: ImportKind::AsyncDynamic,
+        module_id: 0,
+        pattern: None,
+        source_request: AtomStr::default(),
+        span: call.span,
+        type_only: false,
+        symbols: vec![],
+    };
+
+    // import.meta.glob()
+    if matches!(
+        callee.object(),
+        Expression::MetaProperty(meta) if meta.meta.name == "import" && meta.property.name == "meta"
+    ) && callee.static_property_name() == Some("glob")
+    {
+        let mut patterns = vec![];
+
+        match call.arguments.first()? {
+            Argument::Expression(Expression::ArrayExpression(array)) => {
+                for element in &array.elements {
+                    if let ArrayExpressionElement::Expression(expr) = element {
+                        patterns.push(extract_static_string(expr)?);
+                    }
+                }
+            }
+            Argument::Expression(expr) => {
+                patterns.push(extract_static_string(expr)?);
+            }
+            _ => {
+                return None;
+            }
+        };
+
+        record.source_request = patterns
+            .iter()
+            .find(|pattern| !pattern.starts_with('!'))?
+            .clone();
+
+        if let Some(Argument::Expression(Expression::ObjectExpression(options))) =
+            call.arguments.get(1)
+        {
+            for prop in &options.properties {
+                let ObjectPropertyKind::ObjectProperty(prop) = prop else {
+                    continue;
+                };
+
+                // { eager: true }
+                if prop.key.is_specific_id("eager") {
+                    if let Expression::BooleanLiteral(eager) = &prop.value {
+                        if eager.value {
+                            record.kind = ImportKind::AsyncStatic;
+                        }
+                    }
+                }
+
+                // { import: 'name' }
+                if prop.key.is_specific_id("import") {
+                    if let Some(name) = extract_static_string(&prop.value) {
+                        record.symbols.push(ImportedSymbol {
+                            kind: if name.as_str() == "default" {
+                                ImportedKind::Default
+                            } else {
+                                ImportedKind::Value
+                            },
+                            source_name: None,
+                            symbol_id: None,
+                            name,
+                        });
+                    }
+                }
+            }
+        }
+
+        // Eager modules are returned as namespace objects
+        if record.kind == ImportKind::AsyncStatic && record.symbols.is_empty() {
+            record.symbols.push(ImportedSymbol {
+                kind: ImportedKind::Namespace,
+                source_name: None,
+                symbol_id: None,
+                name: AtomStr::from("*"),
+            });
+        }
+
+        record.glob = Some(ImportGlob::Patterns(patterns));
+    }
+    // require.context()
+    else if callee.is_specific_member_access("require", "context") {
+        let mut args = call.arguments.iter().map(|arg| match arg {
+            Argument::Expression(expr) => Some(expr),
+            _ => None,
+        });
+
+        let directory = extract_static_string(args.next()??)?;
+        let mut recursive = true;
+        let mut regex = AtomStr::from(r"^\./.*$");
+
+        if let Some(Some(Expression::BooleanLiteral(value))) = args.next() {
+            recursive = value.value;
+        }
+
+        if let Some(Some(Expression::RegExpLiteral(value))) = args.next() {
+            let mut flags = String::new();
+
+            for (flag, ch) in [
+                (RegExpFlags::I, 'i'),
+                (RegExpFlags::M, 'm'),
+                (RegExpFlags::S, 's'),
+            ] {
+                if value.regex.flags.contains(flag) {
+                    flags.push(ch);
+                }
+            }
+
+            regex = if flags.is_empty() {
+                value.regex.pattern.to_atom_str()
+            } else {
+                AtomStr::from(&format!("(?{flags}){}", value.regex.pattern))
+            };
+        }
+
+        // Only lazy modes split the matched modules into chunks
+        record.kind = match args.next().flatten().and_then(extract_static_string) {
+            Some(mode) if mode.as_str() == "lazy" || mode.as_str() == "lazy-once" => {
+                ImportKind::AsyncDynamic
+            }
+            _ => ImportKind::SyncStatic,
+        };
+
+        record.source_request = AtomStr::from(&format!(
+            "{}/{}",
+            directory.trim_end_matches('/'),
+            if recursive { "**/*" } else { "*" }
+        ));
+
+        record.glob = Some(ImportGlob::Context {
+            directory,
+            recursive,
+            regex,
+        });
+    } else {
+        return None;
+    }
+
+    record.pattern = Some(record.source_request.clone());
+
+    Some(record)
+}
+
+/// Return the value of a string or template literal, without any dynamic parts.
+fn extract_static_string(expr: &Expression) -> Option<AtomStr> {
+    match extract_specifier(expr)? {
+        (value, None) => Some(value),
+        _ => None,
+    }
+}
+
+/// Return the specifier of `new URL('./file', import.meta.url)`, which
+/// is resolved relative to the module, instead of the document.
+fn extract_asset_url(expr: &NewExpression) -> Option<(AtomStr, Option<AtomStr>)> {
+    if !expr.callee.is_specific_id("URL") || expr.arguments.len() != 2 {
+        return None;
+    }
+
+    let Argument::Expression(Expression::MemberExpression(base)) = &expr.arguments[1] else {
+        return None;
+    };
+
+    if !matches!(
+        base.object(),
+        Expression::MetaProperty(meta) if meta.meta.name == "import" && meta.property.name == "meta"
+    ) || base.static_property_name() != Some("url")
+    {
+        return None;
+    }
+
+    extract_specifier_from_argument(&expr.arguments[0])
+}
+
+/// Extract `/// <reference path|types|lib="..." />` directives as type-only
+/// imports. Like TypeScript, directives are only recognized in single-line
+/// comments that precede the first statement.
+pub fn extract_reference_directives(
+    module: &mut Module,
+    source: &str,
+    trivias: &Trivias,
+    program: &Program,
+) {
+    let first_statement = program
+        .directives
+        .first()
+        .map(|directive| directive.span.start)
+        .or_else(|| program.body.first().map(|stmt| stmt.span().start))
+        .unwrap_or(u32::MAX);
+
+    for (start, end, kind) in &trivias.comments {
+        if *start >= first_statement {
+            break;
+        }
+
+        // Leading `//` has been removed
+        let Some(text) = source[*start as usize..*end as usize].strip_prefix('/') else {
+            continue;
+        };
+
+        let text = text.trim();
+
+        if !kind.is_single_line() || !text.starts_with("<reference ") || !text.ends_with("/>") {
+            continue;
+        }
+
+        let (kind, source_request) = if let Some(path) = get_reference_attribute(text, "path") {
+            // Paths are always relative, even without a leading `./`
+            (
+                ImportKind::ReferencePath,
+                if path.starts_with('.') || path.starts_with('/') {
+                    AtomStr::from(path)
+                } else {
+                    AtomStr::from(&format!("./{path}"))
+                },
+            )
+        } else if let Some(name) = get_reference_attribute(text, "types") {
+            (ImportKind::ReferenceTypes, AtomStr::from(name))
+        } else if let Some(name) = get_reference_attribute(text, "lib") {
+            (ImportKind::ReferenceLib, AtomStr::from(name))
+        } else {
+            continue;
+        };
+
+        module.imports.push(Import {
+            kind,
+            attributes: None,
+            glob: None,
+            module_id: 0,
+            pattern: None,
+            source_request,
+            span: Span::new(start - 2, *end),
+            type_only: true,
+            symbols: vec![],
+        });
+    }
+}
+
+/// Return the quoted value of an attribute, like `path` in `<reference path="./file" />`.
+fn get_reference_attribute<'text>(text: &'text str, name: &str) -> Option<&'text str> {
+    let index = text.find(&format!(" {name}="))?;
+    let value = &text[index + name.len() + 2..];
+    let quote = value
+        .chars()
+        .next()
+        .filter(|ch| *ch == '"' || *ch == '\'')?;
+    let value = &value[1..];
+
+    value.find(quote).map(|end| &value[..end])
+}
+
+/// Extract the attributes from the options of `import('./file', { with: { type: 'json' } })`.
+fn extract_dynamic_import_attributes(import: &ImportExpression) -> Option<ImportAttributes> {
+    let Some(Expression::ObjectExpression(options)) = import.arguments.first() else {
+        return None;
+    };
+
+    for prop in &options.properties {
+        let ObjectPropertyKind::ObjectProperty(prop) = prop else {
+            continue;
+        };
+
+        let assert = prop.key.is_specific_id("assert");
+
+        if !assert && !prop.key.is_specific_id("with") {
+            continue;
+        }
+
+        let Expression::ObjectExpression(object) = &prop.value else {
+            continue;
+        };
+
+        let mut attributes = ImportAttributes {
+            assert,
+            entries: vec![],
+        };
+
+        for attr in &object.properties {
+            if let ObjectPropertyKind::ObjectProperty(attr) = attr {
+                if let (Some(key), Some(value)) =
+                    (attr.key.static_name(), extract_static_string(&attr.value))
+                {
+                    attributes.entries.push((key.to_atom_str(), value));
+                }
+            }
+        }
+
+        return Some(attributes);
+    }
+
+    None
+}
+
 fn extract_require_from_expression<'expr, 'ast>(
     expr: &'expr Expression<'ast>,
 ) -> Option<&'expr CallExpression<'ast>> {
@@ -596,6 +1091,7 @@ fn export_binding_pattern(binding: &BindingPattern, list: &mut Vec<ExportedSymbo
         BindingPatternKind::BindingIdentifier(ident) => {
             list.push(ExportedSymbol {
                 kind: ExportedKind::Value,
+                exported_name: None,
                 symbol_id: ident.symbol_id.clone().into_inner(),
                 name: ident.name.to_atom_str(),
             });
@@ -44,6 +44,7 @@ impl ModuleSource for JsonModule {
         // The entire document itself is a default export
         export.symbols.push(ExportedSymbol {
             kind: ExportedKind::Default,
+            exported_name: None,
             symbol_id: None,
             name: AtomStr::from("default"),
         });
@@ -53,6 +54,7 @@ impl ModuleSource for JsonModule {
             for key in object.keys() {
                 export.symbols.push(ExportedSymbol {
                     kind: ExportedKind::Value,
+                    exported_name: None,
                     symbol_id: None,
                     name: AtomStr::from(key.as_str()),
                 });
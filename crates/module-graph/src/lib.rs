@@ -1,25 +1,64 @@
 mod atom;
+mod builtin;
 mod css;
 mod dummy;
+mod external;
 mod js;
 mod json;
 mod media;
 mod module;
+#[cfg(feature = "cache")]
+mod module_cache;
 mod module_graph;
+mod module_graph_affected;
+mod module_graph_attributes;
+mod module_graph_barrels;
+mod module_graph_chunks;
+mod module_graph_cycles;
+mod module_graph_dependencies;
 mod module_graph_error;
+mod module_graph_options;
+mod module_graph_runtime;
+mod module_graph_symbols;
+mod module_graph_unreachable;
+mod module_graph_unused_exports;
+mod module_graph_update;
+mod module_graph_visualize;
+mod module_loader;
+#[cfg(feature = "serialize")]
+mod serialize;
 mod text;
+mod tsconfig_paths;
 mod types;
 mod yaml;
 
 pub use atom::*;
+pub use builtin::*;
 pub use css::*;
 pub use dummy::*;
+pub use external::*;
 pub use js::*;
 pub use json::*;
 pub use media::*;
 pub use module::*;
+#[cfg(feature = "cache")]
+pub use module_cache::*;
 pub use module_graph::*;
+pub use module_graph_affected::*;
+pub use module_graph_attributes::*;
+pub use module_graph_barrels::*;
+pub use module_graph_chunks::*;
+pub use module_graph_cycles::*;
+pub use module_graph_dependencies::*;
 pub use module_graph_error::*;
+pub use module_graph_options::*;
+pub use module_graph_runtime::*;
+pub use module_graph_symbols::*;
+pub use module_graph_unreachable::*;
+pub use module_graph_unused_exports::*;
+pub use module_graph_update::*;
+pub use module_graph_visualize::*;
 pub use text::*;
+pub use tsconfig_paths::*;
 pub use types::*;
 pub use yaml::*;
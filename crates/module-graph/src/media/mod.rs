@@ -6,6 +6,7 @@ use std::sync::Arc;
 
 #[derive(Clone, Copy, Debug)]
 pub enum MediaModuleKind {
+    Asset,
     Audio,
     Image,
     Video,
@@ -20,6 +21,7 @@ pub struct MediaModule {
 impl ModuleSource for MediaModule {
     fn kind(&self) -> SourceKind {
         match self.kind {
+            MediaModuleKind::Asset => SourceKind::Asset,
             MediaModuleKind::Audio => SourceKind::Audio,
             MediaModuleKind::Image => SourceKind::Image,
             MediaModuleKind::Video => SourceKind::Video,
@@ -34,22 +36,10 @@ impl ModuleSource for MediaModule {
         module: &mut Module,
         _package_json: Option<Arc<PackageJson>>,
     ) -> Result<Self, ModuleGraphError> {
-        // https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/MIME_types/Common_types
-        let kind = match module.path.extension().and_then(|ext| ext.to_str()) {
-            // Audio
-            Some(
-                "aac" | "mid" | "midi" | "mp3" | "ogg" | "oga" | "mogg" | "opus" | "weba" | "wav",
-            ) => MediaModuleKind::Audio,
-
-            // Images
-            Some(
-                "apng" | "avif" | "bmp" | "gif" | "ico" | "jpg" | "jpeg" | "jpe" | "jif" | "jfif"
-                | "pjpeg" | "pjp" | "png" | "tif" | "tiff" | "webp",
-            ) => MediaModuleKind::Image,
-
-            // Video
-            Some("avi" | "mp4" | "mpeg" | "ogv" | "webm") => MediaModuleKind::Video,
-
+        let kind = match SourceKind::from_path(&module.path) {
+            Some(SourceKind::Audio) => MediaModuleKind::Audio,
+            Some(SourceKind::Image) => MediaModuleKind::Image,
+            Some(SourceKind::Video) => MediaModuleKind::Video,
             _ => {
                 return Err(ModuleGraphError::UnsupportedFileType(module.path.clone()));
             }
@@ -61,3 +51,13 @@ impl ModuleSource for MediaModule {
         })
     }
 }
+
+impl MediaModule {
+    /// Load a file of any type as an opaque asset, without parsing it.
+    pub(crate) fn load_asset(module: &Module) -> Result<Self, ModuleGraphError> {
+        Ok(MediaModule {
+            kind: MediaModuleKind::Asset,
+            source: Arc::new(fs::read_file_bytes(&module.path)?),
+        })
+    }
+}
@@ -1,6 +1,8 @@
 use crate::atom::*;
+use crate::builtin::BuiltinModule;
 use crate::css::CssModule;
 use crate::dummy::DummyModule;
+use crate::external::ExternalModule;
 use crate::js::JavaScriptModule;
 use crate::json::JsonModule;
 use crate::media::MediaModule;
@@ -15,7 +17,10 @@ use std::fmt;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "cache", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "kebab-case"))]
 pub enum ImportedKind {
     Default,       // import name
     DefaultType,   // import type name
@@ -42,10 +47,21 @@ impl ImportedKind {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "cache", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub struct ImportedSymbol {
     pub kind: ImportedKind,
     pub source_name: Option<AtomStr>,
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::symbol_id")
+    )]
+    #[cfg_attr(
+        feature = "cache",
+        serde(deserialize_with = "crate::serialize::deserialize_symbol_id")
+    )]
     pub symbol_id: Option<SymbolId>,
     pub name: AtomStr,
 }
@@ -61,18 +77,105 @@ impl ImportedSymbol {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "cache", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "kebab-case"))]
 pub enum ImportKind {
-    AsyncStatic,  // import
-    AsyncDynamic, // import()
-    SyncStatic,   // require()
+    AsyncStatic,    // import
+    AsyncDynamic,   // import()
+    SyncStatic,     // require()
+    AssetUrl,       // new URL('./file', import.meta.url)
+    Worker,         // new Worker(new URL('./file', import.meta.url))
+    ReferencePath,  // /// <reference path="./file.d.ts" />
+    ReferenceTypes, // /// <reference types="name" />
+    ReferenceLib,   // /// <reference lib="es2015" />
+}
+
+impl ImportKind {
+    /// Is the imported module loaded separately from the importer, instead of
+    /// evaluated along with it? Assets are never evaluated.
+    pub fn is_deferred(&self) -> bool {
+        matches!(self, Self::AsyncDynamic | Self::AssetUrl | Self::Worker)
+    }
+}
+
+/// The attributes of an import, like `{ type: 'json' }`.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "cache", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub struct ImportAttributes {
+    /// Declared with the deprecated `assert` keyword, instead of `with`.
+    pub assert: bool,
+
+    /// Keys and values in declaration order.
+    pub entries: Vec<(AtomStr, AtomStr)>,
+}
+
+impl ImportAttributes {
+    /// Return the value of the attribute with the provided key.
+    pub fn get(&self, key: &str) -> Option<&AtomStr> {
+        self.entries
+            .iter()
+            .find(|(name, _)| name.as_str() == key)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Files that an import matches by a bundler convention, instead of a specifier.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "cache", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub enum ImportGlob {
+    /// `import.meta.glob(['./pages/*.tsx', '!./pages/_*.tsx'])`, with patterns
+    /// relative to the module. Negated patterns start with `!`.
+    Patterns(Vec<AtomStr>),
+
+    /// `require.context('./pages', true, /\.tsx$/)`, with a regular expression
+    /// that each file, relative to the directory and prefixed with `./`, must match.
+    Context {
+        directory: AtomStr,
+        recursive: bool,
+        regex: AtomStr,
+    },
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "cache", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub struct Import {
+    /// Attributes from `import ... with { type: 'json' }`,
+    /// or the options of an `import()` expression.
+    pub attributes: Option<ImportAttributes>,
+
+    /// The files matched by `import.meta.glob()` or `require.context()`,
+    /// which are always expanded into an import of each file.
+    pub glob: Option<ImportGlob>,
+
     pub kind: ImportKind,
+
+    /// The imported module, or `0` when the import has a pattern
+    /// that was not expanded, or references a TypeScript lib.
     pub module_id: ModuleId,
+
+    /// A glob-like pattern for a non-literal specifier, where each dynamic
+    /// part is replaced with `*`, like `./locales/*.js` for
+    /// ``import(`./locales/${lang}.js`)``. Imports that are expanded from
+    /// the pattern keep it, while their specifier is the matched file.
+    pub pattern: Option<AtomStr>,
+
     pub source_request: AtomStr,
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::span")
+    )]
+    #[cfg_attr(
+        feature = "cache",
+        serde(deserialize_with = "crate::serialize::deserialize_span")
+    )]
     pub span: Span,
     pub symbols: Vec<ImportedSymbol>,
     pub type_only: bool,
@@ -82,9 +185,19 @@ impl Import {
     pub fn is_side_effect(&self) -> bool {
         !self.source_request.is_empty() && self.symbols.is_empty()
     }
+
+    /// Is the import erased at runtime? Either `import type`,
+    /// or only types with `import { type A, type B }`.
+    pub fn is_type_only(&self) -> bool {
+        self.type_only
+            || !self.symbols.is_empty() && self.symbols.iter().all(|symbol| symbol.kind.is_type())
+    }
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "cache", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "kebab-case"))]
 pub enum ExportedKind {
     Default,       // export default name
     DefaultType,   // export default T
@@ -112,13 +225,30 @@ impl ExportedKind {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "cache", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub struct ExportedSymbol {
     pub kind: ExportedKind,
+    /// The name the symbol is exported as, when it differs from its
+    /// local `name`, like `export { name as exported_name }`.
+    pub exported_name: Option<AtomStr>,
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::symbol_id")
+    )]
+    #[cfg_attr(
+        feature = "cache",
+        serde(deserialize_with = "crate::serialize::deserialize_symbol_id")
+    )]
     pub symbol_id: Option<SymbolId>,
     pub name: AtomStr,
 }
 
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "cache", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "kebab-case"))]
 pub enum ExportKind {
     #[default]
     Modern, // export
@@ -127,23 +257,68 @@ pub enum ExportKind {
 }
 
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "cache", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub struct Export {
     pub kind: ExportKind,
     pub module_id: Option<ModuleId>,
     pub source: Option<AtomStr>,
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::optional_span")
+    )]
+    #[cfg_attr(
+        feature = "cache",
+        serde(deserialize_with = "crate::serialize::deserialize_optional_span")
+    )]
     pub span: Option<Span>,
     pub symbols: Vec<ExportedSymbol>,
     pub type_only: bool,
 }
 
+impl Export {
+    /// Is the export erased at runtime? Either `export type`,
+    /// or only types with `export { type A, type B }`.
+    pub fn is_type_only(&self) -> bool {
+        self.type_only
+            || !self.symbols.is_empty() && self.symbols.iter().all(|symbol| symbol.kind.is_type())
+    }
+}
+
+/// A failure that occurred while resolving or loading an
+/// imported or exported module, in tolerant mode.
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub struct ModuleDiagnostic {
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::error")
+    )]
+    pub error: Arc<ModuleGraphError>,
+    pub module_id: ModuleId,
+    pub source_request: AtomStr,
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::span")
+    )]
+    pub span: Span,
+}
+
 pub type ModuleId = u32;
 
-#[derive(Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
 pub enum SourceKind {
     #[default]
     Unknown,
+    Asset,
     Audio,
+    Builtin,
     Css,
+    External,
     Image,
     JavaScript,
     Json,
@@ -152,7 +327,35 @@ pub enum SourceKind {
     Yaml,
 }
 
-pub trait ModuleSource: fmt::Debug {
+impl SourceKind {
+    /// Determine the kind of source from the file extension, or return
+    /// `None` if the file type is not supported.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        // https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/MIME_types/Common_types
+        let kind = match path.extension().and_then(|ext| ext.to_str())? {
+            "css" => Self::Css,
+            "js" | "jsx" | "ts" | "tsx" | "mts" | "cts" | "mjs" | "cjs" => Self::JavaScript,
+            "json" | "jsonc" | "json5" => Self::Json,
+            "yaml" | "yml" => Self::Yaml,
+            "gql" | "graphql" | "html" | "less" | "map" | "sass" | "scss" | "styl" | "svg" => {
+                Self::Text
+            }
+            "aac" | "mid" | "midi" | "mp3" | "ogg" | "oga" | "mogg" | "opus" | "weba" | "wav" => {
+                Self::Audio
+            }
+            "apng" | "avif" | "bmp" | "gif" | "ico" | "jpg" | "jpeg" | "jpe" | "jif" | "jfif"
+            | "pjpeg" | "pjp" | "png" | "tif" | "tiff" | "webp" => Self::Image,
+            "avi" | "mp4" | "mpeg" | "ogv" | "webm" => Self::Video,
+            _ => {
+                return None;
+            }
+        };
+
+        Some(kind)
+    }
+}
+
+pub trait ModuleSource: fmt::Debug + Send + Sync {
     fn kind(&self) -> SourceKind;
 
     fn source(&self) -> &[u8];
@@ -167,9 +370,40 @@ pub trait ModuleSource: fmt::Debug {
     fn parse(&mut self, _module: &mut Module) -> Result<(), ModuleGraphError> {
         Ok(())
     }
+
+    /// Return information specific to the file type,
+    /// to include when the module is serialized.
+    #[cfg(feature = "serialize")]
+    fn metadata(&self) -> starbase_utils::json::JsonValue {
+        starbase_utils::json::JsonValue::Null
+    }
+
+    /// Return state that was extracted while parsing, to persist alongside
+    /// imports and exports. Sources without state are never cached.
+    #[cfg(feature = "cache")]
+    fn get_cache_state(&self) -> Option<starbase_utils::json::JsonValue> {
+        None
+    }
+
+    /// Restore state from the cache, in place of parsing. Returns false
+    /// if the state is invalid, in which case the source is parsed.
+    #[cfg(feature = "cache")]
+    fn set_cache_state(&mut self, _state: starbase_utils::json::JsonValue) -> bool {
+        false
+    }
+
+    /// Return the source as a JavaScript module, if it is one.
+    fn as_javascript(&self) -> Option<&JavaScriptModule> {
+        None
+    }
 }
 
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub struct Module {
+    /// List of failures from resolving or loading dependencies.
+    pub diagnostics: Vec<ModuleDiagnostic>,
+
     /// List of symbols being exported, and optionally the module they came from.
     pub exports: Vec<Export>,
 
@@ -192,12 +426,17 @@ pub struct Module {
     pub query: Option<String>,
 
     /// File type specific source information.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::source")
+    )]
     pub source: Box<dyn ModuleSource>,
 }
 
 impl Module {
     pub fn new(path: &Path) -> Self {
         Self {
+            diagnostics: Vec::new(),
             exports: Vec::new(),
             fragment: None,
             id: 0,
@@ -233,14 +472,22 @@ impl Module {
         symbols
     }
 
-    /// Is the module an external file (in node modules)?
+    /// Is the module a Node.js builtin, like `node:fs`?
+    pub fn is_builtin(&self) -> bool {
+        matches!(self.source.kind(), SourceKind::Builtin)
+    }
+
+    /// Is the module an external file (in node modules),
+    /// or an external package boundary?
     pub fn is_external(&self) -> bool {
-        self.path
-            .components()
-            .any(|comp| comp.as_os_str() == "node_modules")
+        matches!(self.source.kind(), SourceKind::External)
+            || self
+                .path
+                .components()
+                .any(|comp| comp.as_os_str() == "node_modules")
     }
 
-    pub(crate) fn load_and_parse_source(
+    pub(crate) fn load_asset(
         &mut self,
         package_json: Option<Arc<PackageJson>>,
     ) -> Result<(), ModuleGraphError> {
@@ -248,20 +495,51 @@ impl Module {
             self.package_name = package.name.clone();
         }
 
+        self.source = Box::new(MediaModule::load_asset(self)?);
+
+        Ok(())
+    }
+
+    pub(crate) fn load_builtin(&mut self) -> Result<(), ModuleGraphError> {
+        self.source = Box::new(BuiltinModule::load(self, None)?);
+
+        Ok(())
+    }
+
+    pub(crate) fn load_external(
+        &mut self,
+        package_json: Arc<PackageJson>,
+    ) -> Result<(), ModuleGraphError> {
+        self.package_name = package_json.name.clone();
+        self.source = Box::new(ExternalModule::load(self, Some(package_json))?);
+
+        Ok(())
+    }
+
+    pub(crate) fn load_source(
+        &mut self,
+        package_json: Option<Arc<PackageJson>>,
+    ) -> Result<Box<dyn ModuleSource>, ModuleGraphError> {
+        if let Some(package) = &package_json {
+            self.package_name = package.name.clone();
+        }
+
+        Ok(match SourceKind::from_path(&self.path) {
+            Some(SourceKind::Css) => Box::new(CssModule::load(self, package_json)?),
+            Some(SourceKind::JavaScript) => Box::new(JavaScriptModule::load(self, package_json)?),
+            Some(SourceKind::Json) => Box::new(JsonModule::load(self, package_json)?),
+            Some(SourceKind::Yaml) => Box::new(YamlModule::load(self, package_json)?),
+            Some(SourceKind::Text) => Box::new(TextModule::load(self, package_json)?),
+            _ => Box::new(MediaModule::load(self, package_json)?),
+        })
+    }
+
+    pub(crate) fn load_and_parse_source(
+        &mut self,
+        package_json: Option<Arc<PackageJson>>,
+    ) -> Result<(), ModuleGraphError> {
         // Load the file
-        let mut source: Box<dyn ModuleSource> =
-            match self.path.extension().and_then(|ext| ext.to_str()) {
-                Some("css") => Box::new(CssModule::load(self, package_json)?),
-                Some("js" | "jsx" | "ts" | "tsx" | "mts" | "cts" | "mjs" | "cjs") => {
-                    Box::new(JavaScriptModule::load(self, package_json)?)
-                }
-                Some("json" | "jsonc" | "json5") => Box::new(JsonModule::load(self, package_json)?),
-                Some("yaml" | "yml") => Box::new(YamlModule::load(self, package_json)?),
-                Some(
-                    "gql" | "graphql" | "html" | "less" | "map" | "sass" | "scss" | "styl" | "svg",
-                ) => Box::new(TextModule::load(self, package_json)?),
-                _ => Box::new(MediaModule::load(self, package_json)?),
-            };
+        let mut source = self.load_source(package_json)?;
 
         // Parse the file then extract imports/exports
         source.parse(self)?;
@@ -277,7 +555,14 @@ impl fmt::Debug for Module {
         // Primarily for snapshots on Windows
         let path = self.path.to_string_lossy().replace('\\', "/");
 
-        f.debug_struct("Module")
+        let mut debug = f.debug_struct("Module");
+
+        // Only in tolerant mode, so avoid noise in snapshots
+        if !self.diagnostics.is_empty() {
+            debug.field("diagnostics", &self.diagnostics);
+        }
+
+        debug
             .field("exports", &self.exports)
             .field("fragment", &self.fragment)
             .field("id", &self.id)
@@ -0,0 +1,117 @@
+use crate::module::{Export, Import, Module};
+use crate::module_graph_error::ModuleGraphError;
+use nodejs_package_json::PackageJson;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use starbase_utils::json::{self, JsonValue};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Entries written by other versions of the crate may have
+/// been extracted differently, so they are never reused.
+const CACHE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedModule {
+    content_hash: String,
+    exports: Vec<Export>,
+    imports: Vec<Import>,
+    path: PathBuf,
+    state: JsonValue,
+    version: String,
+}
+
+/// Persists the imports and exports extracted from a module on disk, keyed by
+/// its path, the hash of its contents, and the version of the crate.
+#[derive(Debug)]
+pub struct ModuleCache {
+    pub dir: PathBuf,
+}
+
+impl ModuleCache {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Load the source of the module, then restore its imports and exports
+    /// from the cache if its contents have not changed. Otherwise parse the
+    /// source and write a new entry.
+    pub(crate) fn load_and_parse_source(
+        &self,
+        module: &mut Module,
+        package_json: Option<Arc<PackageJson>>,
+    ) -> Result<(), ModuleGraphError> {
+        let mut source = module.load_source(package_json)?;
+        let content_hash = hash(source.source());
+
+        if let Some(cached) = self.read(&module.path, &content_hash) {
+            if source.set_cache_state(cached.state) {
+                module.imports = cached.imports;
+                module.exports = cached.exports;
+                module.source = source;
+
+                return Ok(());
+            }
+        }
+
+        source.parse(module)?;
+
+        if let Some(state) = source.get_cache_state() {
+            // Caching is best effort, and should never fail the graph
+            let _ = json::write_file(
+                self.get_entry_path(&module.path),
+                &json::json!({
+                    "contentHash": content_hash,
+                    "exports": module.exports,
+                    "imports": module.imports,
+                    "path": module.path,
+                    "state": state,
+                    "version": CACHE_VERSION,
+                }),
+                false,
+            );
+        }
+
+        module.source = source;
+
+        Ok(())
+    }
+
+    /// Remove all entries from the cache.
+    pub fn clear(&self) -> Result<(), ModuleGraphError> {
+        starbase_utils::fs::remove_dir_all(&self.dir)?;
+
+        Ok(())
+    }
+
+    fn read(&self, path: &Path, content_hash: &str) -> Option<CachedModule> {
+        let entry_path = self.get_entry_path(path);
+
+        if !entry_path.exists() {
+            return None;
+        }
+
+        // Corrupted or outdated entries are treated as a miss
+        let cached: CachedModule = json::read_file(entry_path).ok()?;
+
+        (cached.version == CACHE_VERSION
+            && cached.content_hash == content_hash
+            && cached.path == path)
+            .then_some(cached)
+    }
+
+    fn get_entry_path(&self, path: &Path) -> PathBuf {
+        self.dir.join(format!(
+            "{}.json",
+            hash(path.as_os_str().as_encoded_bytes())
+        ))
+    }
+}
+
+/// Hex encoded SHA-256 digest of the bytes.
+fn hash(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
@@ -1,63 +1,101 @@
+use crate::atom::AtomStr;
 use crate::module_graph_error::ModuleGraphError;
+use crate::module_graph_options::ModuleGraphOptions;
+use crate::module_graph_symbols::{get_imported_name, get_re_exported_name};
+use crate::module_loader::{LoadedModule, ModuleLoader, ResolvedDependency};
+use crate::tsconfig_paths::TsConfigPaths;
 use crate::{module::*, types::FxIndexMap};
 use clean_path::Clean;
 use nodejs_package_json::PackageJson;
-use oxc_resolver::{PackageJson as ResolvedPackageJson, ResolveOptions, Resolver};
+use oxc::span::Span;
+use oxc_resolver::{PackageJson as ResolvedPackageJson, Resolution, Resolver};
 use petgraph::graphmap::GraphMap;
 use petgraph::Directed;
-use rustc_hash::FxHashMap;
-use starbase_utils::json;
+use rustc_hash::{FxHashMap, FxHashSet};
+use starbase_utils::glob::GlobSet;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-#[derive(Debug)]
-pub enum ModuleGraphEdge {
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
+pub enum ModuleGraphEdgeKind {
     Import,
     Export,
 }
 
-pub type ModuleGraphType = GraphMap<ModuleId, ModuleGraphEdge, Directed>;
+/// A single import or re-export from one module to another.
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub struct ModuleGraphEdge {
+    pub kind: ModuleGraphEdgeKind,
+
+    /// How the module is loaded. Re-exports are always static.
+    pub import_kind: ImportKind,
+
+    /// The specifier and its location within the importing module.
+    pub source_request: AtomStr,
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::span")
+    )]
+    pub span: Span,
+
+    /// Names imported or re-exported from the target module, which is
+    /// `default` for default imports, and `*` for namespaces.
+    pub symbols: Vec<AtomStr>,
+
+    /// Whether the import or export only references types.
+    pub type_only: bool,
+}
+
+/// Modules that import the same module multiple times,
+/// like statically and dynamically, have an edge for each.
+pub type ModuleGraphType = GraphMap<ModuleId, Vec<ModuleGraphEdge>, Directed>;
 
 #[derive(Debug)]
 pub struct ModuleGraph {
     pub graph: ModuleGraphType,
     pub modules: FxIndexMap<ModuleId, Arc<Module>>,
+    pub options: ModuleGraphOptions,
     pub packages: FxHashMap<PathBuf, Arc<PackageJson>>,
     pub resolver: Resolver,
+    pub tsconfigs: FxHashMap<PathBuf, Arc<TsConfigPaths>>,
 
-    next_id: u32,
-    paths_to_ids: FxHashMap<PathBuf, ModuleId>,
+    pub(crate) entries: FxHashSet<ModuleId>,
+    pub(crate) failed_modules: FxHashMap<ModuleId, Arc<ModuleGraphError>>,
+    pub(crate) next_id: u32,
+    pub(crate) paths_to_ids: FxHashMap<PathBuf, ModuleId>,
+    pub(crate) ids_to_package_jsons: FxHashMap<ModuleId, Arc<ResolvedPackageJson>>,
+    pub(crate) invalidated: FxHashSet<ModuleId>,
+    pub(crate) unresolved_to_ids: FxHashMap<PathBuf, ModuleId>,
+    dirs_to_tsconfigs: FxHashMap<PathBuf, Option<PathBuf>>,
+    tsconfig_errors: FxHashMap<PathBuf, Arc<ModuleGraphError>>,
 }
 
 impl ModuleGraph {
     pub fn new() -> Self {
+        Self::with_options(ModuleGraphOptions::default())
+    }
+
+    pub fn with_options(options: ModuleGraphOptions) -> Self {
         Self {
             graph: GraphMap::default(),
             modules: FxIndexMap::default(),
             packages: FxHashMap::default(),
-            resolver: Resolver::new(ResolveOptions {
-                condition_names: vec![
-                    "import".into(),
-                    "module".into(),
-                    "require".into(),
-                    "node".into(),
-                    "default".into(),
-                ],
-                extensions: vec![
-                    ".ts".into(),
-                    ".tsx".into(),
-                    ".mts".into(),
-                    ".cts".into(),
-                    ".mjs".into(),
-                    ".cjs".into(),
-                    ".js".into(),
-                    ".jsx".into(),
-                ],
-                main_fields: vec!["module".into(), "main".into()],
-                ..ResolveOptions::default()
-            }),
+            resolver: Resolver::new(options.to_resolve_options()),
+            options,
+            tsconfigs: FxHashMap::default(),
+            entries: FxHashSet::default(),
+            failed_modules: FxHashMap::default(),
             next_id: 1, // Default/empty modules are 0
             paths_to_ids: FxHashMap::default(),
+            ids_to_package_jsons: FxHashMap::default(),
+            invalidated: FxHashSet::default(),
+            unresolved_to_ids: FxHashMap::default(),
+            dirs_to_tsconfigs: FxHashMap::default(),
+            tsconfig_errors: FxHashMap::default(),
         }
     }
 
@@ -66,14 +104,7 @@ impl ModuleGraph {
         parent_dir: &Path,
         specifier: &str,
     ) -> Result<ModuleId, ModuleGraphError> {
-        let resolved_path = self
-            .resolver
-            .resolve(parent_dir, specifier)
-            .map_err(|error| ModuleGraphError::ResolveFailed {
-                dir: parent_dir.to_owned(),
-                specifier: specifier.to_owned(),
-                error: Box::new(error),
-            })?;
+        let resolved_path = self.resolve_module(parent_dir, specifier)?;
 
         self.load_module_at_path(
             resolved_path.path().clean(),
@@ -96,14 +127,118 @@ impl ModuleGraph {
 
         // Module already exists in the graph, avoid duplicates
         if let Some(module_id) = self.paths_to_ids.get(resolved_path) {
+            self.entries.insert(*module_id);
+
             return Ok(*module_id);
         }
 
-        // Load the package.json before the module
-        let package_json = if let Some(json) = package_json {
-            Some(self.load_package_json(&json.realpath)?)
+        // Load and parse the module and all of its dependencies in parallel
+        let skip = self.paths_to_ids.keys().cloned().collect();
+        let mut loaded = self
+            .create_loader()?
+            .load_all(vec![(resolved_path.to_owned(), package_json)], skip);
+
+        // Then link them together serially, so that IDs are deterministic
+        let module_id = self.link_module(resolved_path, query, fragment, &mut loaded)?;
+
+        self.entries.insert(module_id);
+
+        Ok(module_id)
+    }
+
+    /// Return the names of all Node.js builtin modules (without the `node:`
+    /// prefix) that are directly imported or re-exported by the module.
+    pub fn get_builtins_used_by(&self, module_id: ModuleId) -> Vec<&str> {
+        let mut builtins = self
+            .graph
+            .neighbors(module_id)
+            .filter_map(|dep_id| self.get_builtin_name(dep_id))
+            .collect::<Vec<_>>();
+
+        builtins.sort();
+        builtins.dedup();
+        builtins
+    }
+
+    /// Return the names of all Node.js builtin modules that are directly
+    /// imported or re-exported by any module within the package. Package
+    /// boundaries are skipped, as their modules were never loaded.
+    pub fn get_builtins_used_by_package(&self, package_name: &str) -> Vec<&str> {
+        let mut builtins = vec![];
+
+        for module in self.modules.values() {
+            if module.package_name.as_deref() == Some(package_name)
+                && !matches!(module.source.kind(), SourceKind::External)
+            {
+                builtins.extend(self.get_builtins_used_by(module.id));
+            }
+        }
+
+        builtins.sort();
+        builtins.dedup();
+        builtins
+    }
+
+    fn get_builtin_name(&self, module_id: ModuleId) -> Option<&str> {
+        let module = self.modules.get(&module_id)?;
+
+        if module.is_builtin() {
+            module.path.to_str()?.strip_prefix("node:")
         } else {
             None
+        }
+    }
+
+    /// Return all diagnostics collected while loading modules in tolerant
+    /// mode, or for bare specifiers resolved without a tsconfig that failed
+    /// to load, paired with the ID of the module that triggered them.
+    pub fn get_diagnostics(&self) -> Vec<(ModuleId, &ModuleDiagnostic)> {
+        let mut diagnostics = vec![];
+
+        for (module_id, module) in &self.modules {
+            for diagnostic in &module.diagnostics {
+                diagnostics.push((*module_id, diagnostic));
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Return the error for a module that failed to load in tolerant mode.
+    /// The module will exist in the graph as a dummy module.
+    pub fn get_load_error(&self, module_id: ModuleId) -> Option<&ModuleGraphError> {
+        self.failed_modules.get(&module_id).map(|error| &**error)
+    }
+
+    /// Return every import and re-export from one module to another,
+    /// in source order. Imports are listed before re-exports.
+    pub fn get_edges_between(&self, from_id: ModuleId, to_id: ModuleId) -> &[ModuleGraphEdge] {
+        self.graph
+            .edge_weight(from_id, to_id)
+            .map(|edges| edges.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Return the ID of the module at the provided absolute path,
+    /// if it has been loaded into the graph.
+    pub fn get_module_id<P: AsRef<Path>>(&self, path: P) -> Option<ModuleId> {
+        self.paths_to_ids.get(path.as_ref()).copied()
+    }
+
+    fn link_module(
+        &mut self,
+        resolved_path: &Path,
+        query: Option<String>,
+        fragment: Option<String>,
+        loaded: &mut FxHashMap<PathBuf, LoadedModule>,
+    ) -> Result<ModuleId, ModuleGraphError> {
+        // Module already exists in the graph, avoid duplicates
+        if let Some(module_id) = self.paths_to_ids.get(resolved_path) {
+            return Ok(*module_id);
+        }
+
+        let Some(result) = loaded.remove(resolved_path) else {
+            unreachable!("Module {resolved_path:?} was not loaded!");
         };
 
         // Generate the ID and add to the graph
@@ -113,58 +248,375 @@ impl ModuleGraph {
         self.paths_to_ids
             .insert(resolved_path.to_owned(), module_id);
 
-        // Load and parse the module, then add to the graph
-        let mut module = Module::new(resolved_path);
+        self.link_loaded_module(module_id, result, query, fragment, loaded)?;
+
+        Ok(module_id)
+    }
+
+    pub(crate) fn link_loaded_module(
+        &mut self,
+        module_id: ModuleId,
+        result: LoadedModule,
+        query: Option<String>,
+        fragment: Option<String>,
+        loaded: &mut FxHashMap<PathBuf, LoadedModule>,
+    ) -> Result<(), ModuleGraphError> {
+        let mut module = match result.module {
+            Ok(module) => {
+                self.failed_modules.remove(&module_id);
+                module
+            }
+            Err(error) => {
+                if !self.options.tolerant {
+                    return Err(error);
+                }
+
+                // Keep a dummy module in the graph, so that importers
+                // can report the failure as a diagnostic
+                self.failed_modules.insert(module_id, Arc::new(error));
+
+                Module::new(&result.path)
+            }
+        };
+
         module.id = module_id;
         module.fragment = fragment;
         module.query = query;
 
-        module.load_and_parse_source(package_json)?;
+        if let Some(package_json) = result.package_json {
+            self.ids_to_package_jsons.insert(module_id, package_json);
+        }
+
+        let parent_dir = result.path.parent().unwrap();
+        let mut diagnostics = vec![];
 
-        // Load each imported and exported module, then connect edges
-        let parent_dir = resolved_path.parent().unwrap();
+        // Link each imported and exported module, then connect edges
+        for (import, resolution) in module.imports.iter_mut().zip(result.import_resolutions) {
+            // Patterns that were not expanded, and libs, have no target
+            if let Ok(ResolvedDependency::Unlinked) = resolution {
+                continue;
+            }
 
-        for import in module.imports.iter_mut() {
-            import.module_id = self.load_module(parent_dir, &import.source_request)?;
+            import.module_id = match resolution {
+                // Type references are only used for type checking,
+                // so are never fatal, even when not tolerant
+                Err(error) if import.kind == ImportKind::ReferenceTypes => self
+                    .link_failed_dependency(
+                        parent_dir,
+                        &import.source_request,
+                        import.span,
+                        error,
+                        &mut diagnostics,
+                    ),
+                resolution => self.link_dependency(
+                    parent_dir,
+                    &import.source_request,
+                    import.span,
+                    resolution,
+                    &mut diagnostics,
+                    loaded,
+                )?,
+            };
 
-            self.graph
-                .add_edge(module_id, import.module_id, ModuleGraphEdge::Import);
+            self.add_edge(
+                module_id,
+                import.module_id,
+                ModuleGraphEdge {
+                    kind: ModuleGraphEdgeKind::Import,
+                    import_kind: import.kind,
+                    source_request: import.source_request.clone(),
+                    span: import.span,
+                    symbols: import.symbols.iter().map(get_imported_name).collect(),
+                    type_only: import.is_type_only(),
+                },
+            );
         }
 
-        for export in module.exports.iter_mut() {
-            let Some(source) = &export.source else {
+        for (export, resolution) in module.exports.iter_mut().zip(result.export_resolutions) {
+            let (Some(source), Some(resolution)) = (&export.source, resolution) else {
                 continue;
             };
 
-            let dep_module_id = self.load_module(parent_dir, source)?;
+            let dep_module_id = self.link_dependency(
+                parent_dir,
+                source,
+                export.span.unwrap_or_default(),
+                resolution,
+                &mut diagnostics,
+                loaded,
+            )?;
 
             export.module_id = Some(dep_module_id);
 
-            self.graph
-                .add_edge(module_id, dep_module_id, ModuleGraphEdge::Export);
+            let edge = ModuleGraphEdge {
+                kind: ModuleGraphEdgeKind::Export,
+                import_kind: ImportKind::AsyncStatic,
+                source_request: source.clone(),
+                span: export.span.unwrap_or_default(),
+                symbols: export.symbols.iter().map(get_re_exported_name).collect(),
+                type_only: export.is_type_only(),
+            };
+
+            self.add_edge(module_id, dep_module_id, edge);
         }
 
+        // Bare specifiers were resolved without tsconfig paths if the
+        // tsconfig failed to load, so report it for each of them
+        if let Some(error) = self.get_tsconfig_error_for(parent_dir) {
+            let requests = module
+                .imports
+                .iter()
+                .map(|import| (&import.source_request, import.span, import.module_id))
+                .chain(module.exports.iter().filter_map(|export| {
+                    Some((
+                        export.source.as_ref()?,
+                        export.span.unwrap_or_default(),
+                        export.module_id?,
+                    ))
+                }));
+
+            for (source_request, span, dep_module_id) in requests {
+                if dep_module_id != 0
+                    && !source_request.starts_with('.')
+                    && !Path::new(source_request.as_str()).is_absolute()
+                {
+                    diagnostics.push(ModuleDiagnostic {
+                        error: Arc::clone(&error),
+                        module_id: dep_module_id,
+                        source_request: source_request.to_owned(),
+                        span,
+                    });
+                }
+            }
+        }
+
+        module.diagnostics = diagnostics;
+
         // Store the module in the graph
         self.modules.insert(module_id, Arc::new(module));
 
+        Ok(())
+    }
+
+    fn get_tsconfig_error_for(&self, dir: &Path) -> Option<Arc<ModuleGraphError>> {
+        if !self.options.tsconfig_paths {
+            return None;
+        }
+
+        let tsconfig_path = self.dirs_to_tsconfigs.get(dir)?.as_ref()?;
+
+        self.tsconfig_errors.get(tsconfig_path).map(Arc::clone)
+    }
+
+    fn add_edge(&mut self, from_id: ModuleId, to_id: ModuleId, edge: ModuleGraphEdge) {
+        if let Some(edges) = self.graph.edge_weight_mut(from_id, to_id) {
+            edges.push(edge);
+        } else {
+            self.graph.add_edge(from_id, to_id, vec![edge]);
+        }
+    }
+
+    fn link_dependency(
+        &mut self,
+        parent_dir: &Path,
+        source_request: &AtomStr,
+        span: Span,
+        resolution: Result<ResolvedDependency, ModuleGraphError>,
+        diagnostics: &mut Vec<ModuleDiagnostic>,
+        loaded: &mut FxHashMap<PathBuf, LoadedModule>,
+    ) -> Result<ModuleId, ModuleGraphError> {
+        let resolved_path = match resolution {
+            Ok(
+                ResolvedDependency::Module(resolved_path)
+                | ResolvedDependency::Asset(resolved_path),
+            ) => resolved_path,
+            Ok(ResolvedDependency::Builtin(name)) => {
+                return self.link_builtin(&name);
+            }
+            Ok(ResolvedDependency::External { package_json, root }) => {
+                return self.link_external(root, package_json);
+            }
+            Ok(ResolvedDependency::Unlinked) => {
+                return Ok(0);
+            }
+            Err(error) => {
+                if !self.options.tolerant {
+                    return Err(error);
+                }
+
+                return Ok(self.link_failed_dependency(
+                    parent_dir,
+                    source_request,
+                    span,
+                    error,
+                    diagnostics,
+                ));
+            }
+        };
+
+        let dep_module_id = self.link_resolution(resolved_path, loaded)?;
+
+        if let Some(error) = self.failed_modules.get(&dep_module_id) {
+            diagnostics.push(ModuleDiagnostic {
+                error: Arc::clone(error),
+                module_id: dep_module_id,
+                source_request: source_request.to_owned(),
+                span,
+            });
+        }
+
+        Ok(dep_module_id)
+    }
+
+    fn link_failed_dependency(
+        &mut self,
+        parent_dir: &Path,
+        source_request: &AtomStr,
+        span: Span,
+        error: ModuleGraphError,
+        diagnostics: &mut Vec<ModuleDiagnostic>,
+    ) -> ModuleId {
+        let dep_module_id = self.link_unresolved(parent_dir, source_request);
+
+        diagnostics.push(ModuleDiagnostic {
+            error: Arc::new(error),
+            module_id: dep_module_id,
+            source_request: source_request.to_owned(),
+            span,
+        });
+
+        dep_module_id
+    }
+
+    fn link_builtin(&mut self, name: &str) -> Result<ModuleId, ModuleGraphError> {
+        let path = PathBuf::from(format!("node:{name}"));
+
+        if let Some(module_id) = self.paths_to_ids.get(&path) {
+            return Ok(*module_id);
+        }
+
+        let module_id = self.graph.add_node(self.next_id);
+
+        self.next_id += 1;
+        self.paths_to_ids.insert(path.clone(), module_id);
+
+        let mut module = Module::new(&path);
+        module.id = module_id;
+        module.load_builtin()?;
+
+        self.modules.insert(module_id, Arc::new(module));
+
         Ok(module_id)
     }
 
-    pub fn load_package_json<P: AsRef<Path>>(
+    fn link_external(
         &mut self,
-        path: P,
-    ) -> Result<Arc<PackageJson>, ModuleGraphError> {
-        let path = path.as_ref();
+        root: PathBuf,
+        package_json: Arc<PackageJson>,
+    ) -> Result<ModuleId, ModuleGraphError> {
+        if let Some(module_id) = self.paths_to_ids.get(&root) {
+            return Ok(*module_id);
+        }
+
+        let module_id = self.graph.add_node(self.next_id);
+
+        self.next_id += 1;
+        self.paths_to_ids.insert(root.clone(), module_id);
+
+        let mut module = Module::new(&root);
+        module.id = module_id;
+        module.load_external(package_json)?;
 
-        if let Some(json) = self.packages.get(path) {
-            return Ok(Arc::clone(json));
+        self.modules.insert(module_id, Arc::new(module));
+
+        Ok(module_id)
+    }
+
+    fn link_unresolved(&mut self, parent_dir: &Path, source_request: &str) -> ModuleId {
+        // Relative specifiers are unique per directory,
+        // while bare specifiers are shared across the graph
+        let path = if source_request.starts_with('.') {
+            parent_dir.join(source_request).clean()
+        } else {
+            PathBuf::from(source_request)
+        };
+
+        if let Some(module_id) = self.unresolved_to_ids.get(&path) {
+            return *module_id;
         }
 
-        let json: PackageJson = json::read_file(path)?;
-        let data = Arc::new(json);
+        let module_id = self.graph.add_node(self.next_id);
+
+        self.next_id += 1;
+        self.unresolved_to_ids.insert(path.clone(), module_id);
+
+        let mut module = Module::new(&path);
+        module.id = module_id;
+
+        self.modules.insert(module_id, Arc::new(module));
+
+        module_id
+    }
+
+    pub(crate) fn link_resolution(
+        &mut self,
+        resolved_path: Resolution,
+        loaded: &mut FxHashMap<PathBuf, LoadedModule>,
+    ) -> Result<ModuleId, ModuleGraphError> {
+        self.link_module(
+            &resolved_path.path().clean(),
+            resolved_path.query().map(|query| query.to_owned()),
+            resolved_path.fragment().map(|frag| frag.to_owned()),
+            loaded,
+        )
+    }
+
+    pub fn resolve_module(
+        &mut self,
+        parent_dir: &Path,
+        specifier: &str,
+    ) -> Result<Resolution, ModuleGraphError> {
+        self.create_loader()?.resolve(parent_dir, specifier)
+    }
+
+    pub fn load_tsconfig_paths_for(
+        &mut self,
+        dir: &Path,
+    ) -> Result<Option<Arc<TsConfigPaths>>, ModuleGraphError> {
+        Ok(self.create_loader()?.load_tsconfig_paths_for(dir))
+    }
+
+    pub fn load_package_json<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<Arc<PackageJson>, ModuleGraphError> {
+        self.create_loader()?.load_package_json(path.as_ref())
+    }
 
-        self.packages.insert(path.to_path_buf(), Arc::clone(&data));
+    pub(crate) fn create_loader(&mut self) -> Result<ModuleLoader<'_>, ModuleGraphError> {
+        Ok(ModuleLoader {
+            #[cfg(feature = "cache")]
+            cache: self
+                .options
+                .cache_dir
+                .as_ref()
+                .map(crate::module_cache::ModuleCache::new),
+            external_packages: GlobSet::new(&self.options.external_packages)?,
+            options: &self.options,
+            resolver: &self.resolver,
+            types_resolver: self
+                .resolver
+                .clone_with_options(self.options.to_types_resolve_options()),
+            packages: Mutex::new(&mut self.packages),
+            tsconfigs: Mutex::new(&mut self.tsconfigs),
+            dirs_to_tsconfigs: Mutex::new(&mut self.dirs_to_tsconfigs),
+            tsconfig_errors: Mutex::new(&mut self.tsconfig_errors),
+        })
+    }
+}
 
-        Ok(data)
+impl Default for ModuleGraph {
+    fn default() -> Self {
+        Self::new()
     }
 }
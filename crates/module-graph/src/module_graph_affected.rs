@@ -0,0 +1,126 @@
+use crate::module::ModuleId;
+use crate::module_graph::ModuleGraph;
+use crate::module_graph_dependencies::DependencyQueryOptions;
+use crate::module_graph_error::ModuleGraphError;
+use clean_path::Clean;
+use rustc_hash::FxHashSet;
+use starbase_utils::glob::GlobSet;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Default)]
+pub struct AffectedModulesOptions {
+    /// Ignore `import()` expressions, so that lazily loaded
+    /// modules do not affect their importers.
+    pub ignore_dynamic_imports: bool,
+
+    /// Ignore imports and exports that only reference types,
+    /// as they are erased at runtime.
+    pub ignore_type_only: bool,
+
+    /// Globs of modules to return, relative to the root, like `**/*.test.ts`.
+    /// When empty, all affected modules are returned.
+    pub include: Vec<String>,
+}
+
+impl AffectedModulesOptions {
+    pub fn with_ignore_dynamic_imports(mut self, enabled: bool) -> Self {
+        self.ignore_dynamic_imports = enabled;
+        self
+    }
+
+    pub fn with_ignore_type_only(mut self, enabled: bool) -> Self {
+        self.ignore_type_only = enabled;
+        self
+    }
+
+    pub fn with_include<I: IntoIterator<Item = V>, V: AsRef<str>>(mut self, globs: I) -> Self {
+        self.include = globs.into_iter().map(|g| g.as_ref().to_owned()).collect();
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct AffectedModules {
+    /// Changed files that are modules within the graph.
+    pub changed: Vec<ModuleId>,
+
+    /// Entry modules that are changed, or depend on a changed module.
+    /// The include globs do not apply to entries.
+    pub entries: Vec<ModuleId>,
+
+    /// Modules that are changed, or depend on a changed module,
+    /// and match the include globs.
+    pub modules: Vec<ModuleId>,
+
+    /// Changed files that are not part of the graph.
+    pub unknown_files: Vec<PathBuf>,
+}
+
+impl ModuleGraph {
+    /// Determine which modules are affected by the changed files, by following
+    /// imports and re-exports in reverse. Changed files may be relative to the
+    /// root, or absolute. All returned IDs are sorted.
+    pub fn get_affected_modules<P: AsRef<Path>, F: AsRef<Path>>(
+        &self,
+        root: P,
+        changed_files: &[F],
+        options: &AffectedModulesOptions,
+    ) -> Result<AffectedModules, ModuleGraphError> {
+        let root = root.as_ref();
+        let mut affected = AffectedModules::default();
+
+        for file in changed_files {
+            let path = root.join(file.as_ref()).clean();
+
+            match self.get_module_id(&path) {
+                Some(module_id) => affected.changed.push(module_id),
+                None => affected.unknown_files.push(path),
+            };
+        }
+
+        let query = DependencyQueryOptions::default()
+            .with_ignore_dynamic_imports(options.ignore_dynamic_imports)
+            .with_ignore_type_only(options.ignore_type_only);
+
+        let mut seen = FxHashSet::default();
+        let mut queue = affected.changed.clone();
+
+        while let Some(module_id) = queue.pop() {
+            if !seen.insert(module_id) {
+                continue;
+            }
+
+            queue.extend(
+                self.get_dependency_edges(module_id, &query, true)
+                    .into_iter()
+                    .map(|edge| edge.module_id),
+            );
+        }
+
+        let include = GlobSet::new(&options.include)?;
+
+        for module_id in seen {
+            if self.entries.contains(&module_id) {
+                affected.entries.push(module_id);
+            }
+
+            if options.include.is_empty()
+                || self.modules.get(&module_id).is_some_and(|module| {
+                    module
+                        .path
+                        .strip_prefix(root)
+                        .is_ok_and(|path| include.matches(path))
+                })
+            {
+                affected.modules.push(module_id);
+            }
+        }
+
+        affected.changed.sort();
+        affected.changed.dedup();
+        affected.entries.sort();
+        affected.modules.sort();
+
+        Ok(affected)
+    }
+}
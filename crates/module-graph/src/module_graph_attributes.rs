@@ -0,0 +1,112 @@
+use crate::atom::AtomStr;
+use crate::module::{Import, ImportKind, Module, ModuleId, SourceKind};
+use crate::module_graph::ModuleGraph;
+use oxc::span::Span;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ImportAttributeIssue {
+    /// A JSON module is imported from an ES module in Node.js,
+    /// without the required `type: 'json'` attribute.
+    MissingJsonType,
+
+    /// The `type` attribute doesn't match the kind of the imported
+    /// module, like `type: 'css'` for a JavaScript file.
+    TypeMismatch { attribute_type: AtomStr },
+}
+
+/// An import whose attributes don't match the imported module.
+#[derive(Debug)]
+pub struct ImportAttributeMismatch {
+    pub issue: ImportAttributeIssue,
+
+    /// The importing module.
+    pub module_id: ModuleId,
+
+    /// The specifier and its location within the importing module.
+    pub source_request: AtomStr,
+    pub span: Span,
+
+    /// The imported module, and its kind.
+    pub target_id: ModuleId,
+    pub target_kind: SourceKind,
+}
+
+impl ModuleGraph {
+    /// Validate the attributes of each import against the kind of the imported
+    /// module. The `json` and `css` types must only be used for modules of the
+    /// same kind, and ES modules in Node.js must use `type: 'json'` to import JSON.
+    /// Type-only imports, requires, and unlinked imports are ignored.
+    pub fn get_import_attribute_mismatches(&self) -> Vec<ImportAttributeMismatch> {
+        let mut ids = self.modules.keys().copied().collect::<Vec<_>>();
+        ids.sort();
+
+        let mut mismatches = vec![];
+
+        for module_id in ids {
+            let module = &self.modules[&module_id];
+
+            for import in &module.imports {
+                if import.type_only
+                    || !matches!(
+                        import.kind,
+                        ImportKind::AsyncStatic | ImportKind::AsyncDynamic
+                    )
+                {
+                    continue;
+                }
+
+                let Some(target) = self.modules.get(&import.module_id) else {
+                    continue;
+                };
+
+                let target_kind = target.source.kind();
+
+                if let Some(issue) = validate_import_attributes(module, import, target_kind) {
+                    mismatches.push(ImportAttributeMismatch {
+                        issue,
+                        module_id,
+                        source_request: import.source_request.clone(),
+                        span: import.span,
+                        target_id: import.module_id,
+                        target_kind,
+                    });
+                }
+            }
+        }
+
+        mismatches
+    }
+}
+
+fn validate_import_attributes(
+    module: &Module,
+    import: &Import,
+    target_kind: SourceKind,
+) -> Option<ImportAttributeIssue> {
+    let attribute_type = import
+        .attributes
+        .as_ref()
+        .and_then(|attributes| attributes.get("type"));
+
+    match attribute_type {
+        Some(attribute_type) => {
+            let expected_kind = match attribute_type.as_str() {
+                "json" => SourceKind::Json,
+                "css" => SourceKind::Css,
+                _ => {
+                    return None;
+                }
+            };
+
+            (target_kind != expected_kind).then(|| ImportAttributeIssue::TypeMismatch {
+                attribute_type: attribute_type.clone(),
+            })
+        }
+        None => (target_kind == SourceKind::Json
+            && module
+                .source
+                .as_javascript()
+                .is_some_and(|js| js.package_type.is_esm()))
+        .then_some(ImportAttributeIssue::MissingJsonType),
+    }
+}
@@ -0,0 +1,129 @@
+use crate::atom::AtomStr;
+use crate::module::ModuleId;
+use crate::module_graph::ModuleGraph;
+use crate::module_graph_symbols::SymbolResolution;
+use oxc::span::Span;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::path::PathBuf;
+
+/// An import of a barrel file, and the modules that
+/// actually declare the imported symbols.
+#[derive(Debug)]
+pub struct BarrelImport {
+    /// The importing module.
+    pub module_id: ModuleId,
+
+    /// The specifier and its location within the importing module.
+    pub source_request: AtomStr,
+    pub span: Span,
+
+    /// Modules that declare the imported symbols. Namespace and side-effect
+    /// imports depend on every module that the barrel re-exports.
+    pub source_modules: Vec<ModuleId>,
+}
+
+/// A module that only re-exports other modules.
+#[derive(Debug)]
+pub struct BarrelFile {
+    pub module_id: ModuleId,
+    pub path: PathBuf,
+
+    /// Unique modules that import the barrel.
+    pub importers: Vec<ModuleId>,
+
+    /// Every import of the barrel, across all modules.
+    pub imports: Vec<BarrelImport>,
+
+    /// Modules that are loaded when the barrel is imported.
+    pub re_exported_modules: Vec<ModuleId>,
+}
+
+impl ModuleGraph {
+    /// Find all barrel files, as determined by [`JavaScriptModule::is_barrel_file`]
+    /// with the provided threshold, and report how they are imported.
+    ///
+    /// [`JavaScriptModule::is_barrel_file`]: crate::JavaScriptModule::is_barrel_file
+    pub fn get_barrel_files(&self, threshold: usize) -> Vec<BarrelFile> {
+        let mut ids = self.modules.keys().copied().collect::<Vec<_>>();
+        ids.sort();
+
+        let mut barrels = FxHashMap::default();
+
+        for module_id in &ids {
+            let module = &self.modules[module_id];
+
+            if !module
+                .source
+                .as_javascript()
+                .is_some_and(|js| js.is_barrel_file(threshold))
+            {
+                continue;
+            }
+
+            let mut re_exported_modules = self.graph.neighbors(*module_id).collect::<Vec<_>>();
+            re_exported_modules.sort();
+
+            barrels.insert(
+                *module_id,
+                BarrelFile {
+                    module_id: *module_id,
+                    path: module.path.clone(),
+                    importers: vec![],
+                    imports: vec![],
+                    re_exported_modules,
+                },
+            );
+        }
+
+        for module_id in &ids {
+            let module = &self.modules[module_id];
+
+            for import in &module.imports {
+                let Some(barrel) = barrels.get_mut(&import.module_id) else {
+                    continue;
+                };
+
+                let mut source_modules = FxHashSet::default();
+
+                if import.symbols.is_empty() {
+                    source_modules.extend(barrel.re_exported_modules.iter().copied());
+                }
+
+                for symbol in &import.symbols {
+                    match self.resolve_imported_symbol(
+                        import.module_id,
+                        symbol,
+                        &mut FxHashSet::default(),
+                    ) {
+                        SymbolResolution::Namespace { module_id }
+                            if module_id == barrel.module_id =>
+                        {
+                            source_modules.extend(barrel.re_exported_modules.iter().copied());
+                        }
+                        resolution => {
+                            source_modules.extend(resolution.module_id());
+                        }
+                    };
+                }
+
+                let mut source_modules = source_modules.into_iter().collect::<Vec<_>>();
+                source_modules.sort();
+
+                if barrel.importers.last() != Some(module_id) {
+                    barrel.importers.push(*module_id);
+                }
+
+                barrel.imports.push(BarrelImport {
+                    module_id: *module_id,
+                    source_request: import.source_request.clone(),
+                    span: import.span,
+                    source_modules,
+                });
+            }
+        }
+
+        let mut barrels = barrels.into_values().collect::<Vec<_>>();
+        barrels.sort_by_key(|barrel| barrel.module_id);
+        barrels
+    }
+}
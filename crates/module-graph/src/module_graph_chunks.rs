@@ -0,0 +1,230 @@
+use crate::module::{ImportKind, ModuleId, SourceKind};
+use crate::module_graph::ModuleGraph;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::VecDeque;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ChunkKind {
+    /// Started by an entry module.
+    Entry,
+
+    /// Started by the target of an `import()` expression.
+    Async,
+
+    /// Started by the target of a `new Worker()` expression,
+    /// and loaded in a separate context.
+    Worker,
+}
+
+/// A group of modules that a bundler would emit and load together.
+#[derive(Debug)]
+pub struct Chunk {
+    /// Index of the chunk within [`ChunkGraph::chunks`].
+    pub id: usize,
+    pub kind: ChunkKind,
+
+    /// The module that starts the chunk.
+    pub root_id: ModuleId,
+
+    /// Modules that are statically loaded by the root module,
+    /// including the root itself. IDs are sorted.
+    pub modules: Vec<ModuleId>,
+
+    /// Async and worker chunks that are loaded from this chunk.
+    pub children: Vec<usize>,
+
+    /// Combined size of the source of all modules, in bytes.
+    pub size: usize,
+}
+
+/// A module that is included in multiple chunks, and would
+/// typically be split into a common chunk by a bundler.
+#[derive(Debug)]
+pub struct SharedModule {
+    pub module_id: ModuleId,
+
+    /// Chunks that include the module.
+    pub chunks: Vec<usize>,
+
+    /// Size of the module source, in bytes.
+    pub size: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct ChunkGraph {
+    /// Entry chunks in the order of the entries, followed by async
+    /// and worker chunks in the order they were discovered.
+    pub chunks: Vec<Chunk>,
+
+    /// Sorted by module ID.
+    pub shared_modules: Vec<SharedModule>,
+}
+
+impl Chunk {
+    fn new(id: usize, kind: ChunkKind, root_id: ModuleId) -> Self {
+        Self {
+            id,
+            kind,
+            root_id,
+            modules: vec![],
+            children: vec![],
+            size: 0,
+        }
+    }
+}
+
+impl ChunkGraph {
+    /// Return the chunks that include the module.
+    pub fn get_chunks_for(&self, module_id: ModuleId) -> Vec<&Chunk> {
+        self.chunks
+            .iter()
+            .filter(|chunk| chunk.modules.binary_search(&module_id).is_ok())
+            .collect()
+    }
+}
+
+impl ModuleGraph {
+    /// Estimate the chunks that a bundler would produce for the entry modules.
+    /// Each `import()` of a module that is not already within the importing
+    /// chunk starts an async chunk, and each worker entry starts a worker chunk.
+    /// Type-only imports and asset URLs are ignored, and externals and
+    /// builtins are never included in a chunk.
+    pub fn get_chunks(&self, entries: &[ModuleId]) -> ChunkGraph {
+        let mut graph = ChunkGraph::default();
+        let mut roots_to_chunks = FxHashMap::<ModuleId, usize>::default();
+        let mut queue = VecDeque::new();
+
+        for entry_id in entries {
+            if roots_to_chunks.contains_key(entry_id) {
+                continue;
+            }
+
+            let id = graph.chunks.len();
+
+            graph
+                .chunks
+                .push(Chunk::new(id, ChunkKind::Entry, *entry_id));
+            roots_to_chunks.insert(*entry_id, id);
+            queue.push_back(id);
+        }
+
+        while let Some(id) = queue.pop_front() {
+            let (modules, dynamic_ids) = self.collect_chunk_modules(graph.chunks[id].root_id);
+            let mut children = vec![];
+
+            for (dynamic_id, kind) in dynamic_ids {
+                let child_id = *roots_to_chunks.entry(dynamic_id).or_insert_with(|| {
+                    let child_id = graph.chunks.len();
+
+                    graph.chunks.push(Chunk::new(child_id, kind, dynamic_id));
+                    queue.push_back(child_id);
+
+                    child_id
+                });
+
+                if !children.contains(&child_id) {
+                    children.push(child_id);
+                }
+            }
+
+            let chunk = &mut graph.chunks[id];
+            chunk.size = modules.iter().map(|id| self.get_source_size(*id)).sum();
+            chunk.modules = modules;
+            chunk.children = children;
+        }
+
+        let mut modules_to_chunks = FxHashMap::<ModuleId, Vec<usize>>::default();
+
+        for chunk in &graph.chunks {
+            for module_id in &chunk.modules {
+                modules_to_chunks
+                    .entry(*module_id)
+                    .or_default()
+                    .push(chunk.id);
+            }
+        }
+
+        for (module_id, chunks) in modules_to_chunks {
+            if chunks.len() > 1 {
+                graph.shared_modules.push(SharedModule {
+                    module_id,
+                    chunks,
+                    size: self.get_source_size(module_id),
+                });
+            }
+        }
+
+        graph.shared_modules.sort_by_key(|shared| shared.module_id);
+        graph
+    }
+
+    /// Follow static runtime edges from the root module, and return the
+    /// modules found, and the targets of dynamic imports outside of them,
+    /// and of worker entries, with the kind of chunk they start.
+    fn collect_chunk_modules(
+        &self,
+        root_id: ModuleId,
+    ) -> (Vec<ModuleId>, Vec<(ModuleId, ChunkKind)>) {
+        let mut modules = FxHashSet::default();
+        let mut dynamic_ids = vec![];
+        let mut stack = vec![root_id];
+
+        while let Some(module_id) = stack.pop() {
+            let Some(module) = self.modules.get(&module_id) else {
+                continue;
+            };
+
+            if matches!(
+                module.source.kind(),
+                SourceKind::Builtin | SourceKind::External
+            ) || !modules.insert(module_id)
+            {
+                continue;
+            }
+
+            let mut static_ids = vec![];
+
+            for (_, dep_module_id, edges) in self.graph.edges(module_id) {
+                let mut runtime_edges = edges.iter().filter(|edge| !edge.type_only);
+
+                if runtime_edges
+                    .clone()
+                    .any(|edge| !edge.import_kind.is_deferred())
+                {
+                    static_ids.push(dep_module_id);
+                } else if runtime_edges
+                    .clone()
+                    .any(|edge| edge.import_kind == ImportKind::AsyncDynamic)
+                {
+                    dynamic_ids.push((dep_module_id, ChunkKind::Async));
+                }
+
+                // Workers run in their own context, even when already loaded
+                if runtime_edges.any(|edge| edge.import_kind == ImportKind::Worker) {
+                    dynamic_ids.push((dep_module_id, ChunkKind::Worker));
+                }
+            }
+
+            // Reversed so that imports are visited in source order
+            stack.extend(static_ids.into_iter().rev());
+        }
+
+        // Dynamic imports of modules within the chunk are already loaded
+        dynamic_ids.retain(|(id, kind)| *kind == ChunkKind::Worker || !modules.contains(id));
+
+        let mut seen = FxHashSet::default();
+        dynamic_ids.retain(|id| seen.insert(*id));
+
+        let mut modules = modules.into_iter().collect::<Vec<_>>();
+        modules.sort();
+
+        (modules, dynamic_ids)
+    }
+
+    fn get_source_size(&self, module_id: ModuleId) -> usize {
+        self.modules
+            .get(&module_id)
+            .map(|module| module.source.source().len())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,202 @@
+use crate::atom::AtomStr;
+use crate::module::{Export, Import, ModuleId};
+use crate::module_graph::ModuleGraph;
+use oxc::span::Span;
+use petgraph::algo::tarjan_scc;
+use petgraph::graphmap::DiGraphMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::hash_map::Entry;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Default)]
+pub struct ModuleCycleOptions {
+    /// Ignore `import()` expressions, asset URLs, and worker entries,
+    /// as they are evaluated lazily, if at all.
+    pub ignore_dynamic_imports: bool,
+
+    /// Ignore imports and exports that only reference types,
+    /// as they are erased at runtime.
+    pub ignore_type_only: bool,
+}
+
+impl ModuleCycleOptions {
+    pub fn with_ignore_dynamic_imports(mut self, enabled: bool) -> Self {
+        self.ignore_dynamic_imports = enabled;
+        self
+    }
+
+    pub fn with_ignore_type_only(mut self, enabled: bool) -> Self {
+        self.ignore_type_only = enabled;
+        self
+    }
+
+    fn includes_import(&self, import: &Import) -> bool {
+        if self.ignore_dynamic_imports && import.kind.is_deferred() {
+            return false;
+        }
+
+        !(self.ignore_type_only && import.is_type_only())
+    }
+
+    fn includes_export(&self, export: &Export) -> bool {
+        !(self.ignore_type_only && export.is_type_only())
+    }
+}
+
+/// An import or re-export from one module to the next within a cycle.
+#[derive(Debug)]
+pub struct ModuleCycleEdge {
+    /// The importing module.
+    pub module_id: ModuleId,
+    pub path: PathBuf,
+
+    /// The specifier and its location within the importing module.
+    pub source_request: AtomStr,
+    pub span: Span,
+
+    /// The imported module, which is the next module in the cycle.
+    pub target_id: ModuleId,
+}
+
+/// A group of modules that circularly depend on each other, and a circular
+/// chain of imports through them. The last edge points back to the module
+/// of the first edge.
+#[derive(Debug)]
+pub struct ModuleCycle {
+    /// The shortest chain of imports from the module with the lowest ID
+    /// back to itself. Other chains may exist between the modules.
+    pub edges: Vec<ModuleCycleEdge>,
+
+    /// All modules in the strongly connected component, sorted by ID.
+    /// Every module can reach every other module through imports.
+    pub modules: Vec<ModuleId>,
+}
+
+impl ModuleCycle {
+    /// Return the paths of all modules in the cycle, in import order.
+    pub fn paths(&self) -> Vec<&Path> {
+        self.edges.iter().map(|edge| edge.path.as_path()).collect()
+    }
+}
+
+impl ModuleGraph {
+    /// Find all circular dependencies in the graph. A cycle is reported for
+    /// each strongly connected component, with all of its modules, and a
+    /// representative chain of imports: starting from the module with the
+    /// lowest ID, and following the shortest path back to it. Elementary
+    /// cycles are not enumerated, as their number grows exponentially.
+    pub fn get_cycles(&self, options: &ModuleCycleOptions) -> Vec<ModuleCycle> {
+        // Rebuild the graph with only the edges that are relevant,
+        // and keep the first import or export that formed each edge
+        let mut graph = DiGraphMap::<ModuleId, ()>::new();
+        let mut requests = FxHashMap::<(ModuleId, ModuleId), (&AtomStr, Span)>::default();
+
+        for module in self.modules.values() {
+            graph.add_node(module.id);
+
+            let imports = module
+                .imports
+                .iter()
+                .filter(|import| options.includes_import(import))
+                .map(|import| (import.module_id, &import.source_request, import.span));
+
+            let exports = module
+                .exports
+                .iter()
+                .filter(|export| options.includes_export(export))
+                .filter_map(|export| {
+                    Some((
+                        export.module_id?,
+                        export.source.as_ref()?,
+                        export.span.unwrap_or_default(),
+                    ))
+                });
+
+            for (dep_module_id, source_request, span) in imports.chain(exports) {
+                graph.add_edge(module.id, dep_module_id, ());
+                requests
+                    .entry((module.id, dep_module_id))
+                    .or_insert((source_request, span));
+            }
+        }
+
+        let mut cycles = vec![];
+
+        for component in tarjan_scc(&graph) {
+            let start_id = *component.iter().min().unwrap();
+
+            if component.len() == 1 && !graph.contains_edge(start_id, start_id) {
+                continue;
+            }
+
+            let mut modules = component;
+            modules.sort();
+
+            let members = modules.iter().copied().collect::<FxHashSet<_>>();
+
+            let edges = find_shortest_cycle(&graph, &members, start_id)
+                .into_iter()
+                .map(|(module_id, target_id)| {
+                    let (source_request, span) = requests[&(module_id, target_id)];
+
+                    ModuleCycleEdge {
+                        module_id,
+                        path: self.modules[&module_id].path.clone(),
+                        source_request: source_request.to_owned(),
+                        span,
+                        target_id,
+                    }
+                })
+                .collect();
+
+            cycles.push(ModuleCycle { edges, modules });
+        }
+
+        cycles.sort_by_key(|cycle| cycle.edges[0].module_id);
+        cycles
+    }
+}
+
+/// Breadth-first search from the starting module back to itself, without
+/// leaving the strongly connected component.
+fn find_shortest_cycle(
+    graph: &DiGraphMap<ModuleId, ()>,
+    members: &FxHashSet<ModuleId>,
+    start_id: ModuleId,
+) -> Vec<(ModuleId, ModuleId)> {
+    let mut parents = FxHashMap::<ModuleId, ModuleId>::default();
+    let mut queue = VecDeque::from([start_id]);
+
+    while let Some(module_id) = queue.pop_front() {
+        for dep_module_id in graph.neighbors(module_id) {
+            if !members.contains(&dep_module_id) {
+                continue;
+            }
+
+            if dep_module_id == start_id {
+                let mut edges = vec![(module_id, start_id)];
+                let mut current_id = module_id;
+
+                while current_id != start_id {
+                    let parent_id = parents[&current_id];
+
+                    edges.push((parent_id, current_id));
+                    current_id = parent_id;
+                }
+
+                edges.reverse();
+
+                return edges;
+            }
+
+            if let Entry::Vacant(entry) = parents.entry(dep_module_id) {
+                entry.insert(module_id);
+                queue.push_back(dep_module_id);
+            }
+        }
+    }
+
+    // Every member of a strongly connected component can reach the others
+    unreachable!("Module {start_id} is not part of a cycle!");
+}
@@ -0,0 +1,240 @@
+use crate::atom::AtomStr;
+use crate::module::{ImportKind, Module, ModuleId};
+use crate::module_graph::{ModuleGraph, ModuleGraphEdgeKind};
+use crate::types::FxIndexMap;
+use oxc::span::Span;
+use petgraph::Direction::{Incoming, Outgoing};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, Default)]
+pub struct DependencyQueryOptions {
+    /// Maximum number of edges to traverse for transitive queries,
+    /// where `1` only returns direct relationships.
+    pub depth: Option<usize>,
+
+    /// Ignore `import()` expressions, asset URLs, and worker entries,
+    /// as they are evaluated lazily, if at all.
+    pub ignore_dynamic_imports: bool,
+
+    /// Ignore external packages, files within `node_modules`,
+    /// and Node.js builtins.
+    pub ignore_externals: bool,
+
+    /// Ignore imports and exports that only reference types,
+    /// as they are erased at runtime.
+    pub ignore_type_only: bool,
+}
+
+impl DependencyQueryOptions {
+    pub fn with_depth(mut self, depth: usize) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    pub fn with_ignore_dynamic_imports(mut self, enabled: bool) -> Self {
+        self.ignore_dynamic_imports = enabled;
+        self
+    }
+
+    pub fn with_ignore_externals(mut self, enabled: bool) -> Self {
+        self.ignore_externals = enabled;
+        self
+    }
+
+    pub fn with_ignore_type_only(mut self, enabled: bool) -> Self {
+        self.ignore_type_only = enabled;
+        self
+    }
+
+    fn includes_edge(&self, edge: &DependencyEdge) -> bool {
+        !(self.ignore_dynamic_imports && edge.kind.is_some_and(|kind| kind.is_deferred())
+            || self.ignore_type_only && edge.type_only)
+    }
+
+    fn includes_module(&self, module: &Module) -> bool {
+        !(self.ignore_externals && (module.is_external() || module.is_builtin()))
+    }
+}
+
+/// An import or re-export record from one module to another.
+#[derive(Debug)]
+pub struct DependencyEdge {
+    /// The importing module.
+    pub module_id: ModuleId,
+
+    /// The specifier and its location within the importing module.
+    pub source_request: AtomStr,
+    pub span: Span,
+
+    /// The imported module.
+    pub target_id: ModuleId,
+
+    /// How the module is imported, or `None` for re-exports.
+    pub kind: Option<ImportKind>,
+
+    /// Whether the import or export only references types.
+    pub type_only: bool,
+}
+
+/// A module that was reached by a dependency or dependent query.
+#[derive(Debug)]
+pub struct ModuleDependency {
+    pub module_id: ModuleId,
+    pub path: PathBuf,
+
+    /// Number of edges between the queried module and this module.
+    pub depth: usize,
+
+    /// The shortest chain of modules from the queried module to this
+    /// module, including both ends.
+    pub chain: Vec<ModuleId>,
+
+    /// Every import and re-export between this module and the
+    /// previous module in the chain.
+    pub edges: Vec<DependencyEdge>,
+}
+
+impl ModuleGraph {
+    /// Return the modules that are directly imported or re-exported by the module.
+    pub fn dependencies_of(
+        &self,
+        module_id: ModuleId,
+        options: &DependencyQueryOptions,
+    ) -> Vec<ModuleDependency> {
+        self.query_dependencies(module_id, options, Some(1), false)
+    }
+
+    /// Return the modules that directly import or re-export the module.
+    pub fn dependents_of(
+        &self,
+        module_id: ModuleId,
+        options: &DependencyQueryOptions,
+    ) -> Vec<ModuleDependency> {
+        self.query_dependencies(module_id, options, Some(1), true)
+    }
+
+    /// Return all modules that are imported by the module, directly or through
+    /// other modules, up to the configured depth. Modules are returned in
+    /// breadth-first order.
+    pub fn transitive_dependencies_of(
+        &self,
+        module_id: ModuleId,
+        options: &DependencyQueryOptions,
+    ) -> Vec<ModuleDependency> {
+        self.query_dependencies(module_id, options, options.depth, false)
+    }
+
+    /// Return all modules that import the module, directly or through
+    /// other modules, up to the configured depth. Modules are returned in
+    /// breadth-first order.
+    pub fn transitive_dependents_of(
+        &self,
+        module_id: ModuleId,
+        options: &DependencyQueryOptions,
+    ) -> Vec<ModuleDependency> {
+        self.query_dependencies(module_id, options, options.depth, true)
+    }
+
+    fn query_dependencies(
+        &self,
+        module_id: ModuleId,
+        options: &DependencyQueryOptions,
+        max_depth: Option<usize>,
+        reverse: bool,
+    ) -> Vec<ModuleDependency> {
+        let mut results = vec![];
+        let mut chains = FxHashMap::<ModuleId, Vec<ModuleId>>::default();
+        let mut seen = FxHashSet::from_iter([module_id]);
+        let mut queue = VecDeque::from([module_id]);
+
+        chains.insert(module_id, vec![module_id]);
+
+        while let Some(current_id) = queue.pop_front() {
+            let depth = chains[&current_id].len();
+
+            if max_depth.is_some_and(|max| depth > max) {
+                continue;
+            }
+
+            let edges = self.get_dependency_edges(current_id, options, reverse);
+
+            // Multiple imports of the same module are reported together
+            let mut edges_by_module = FxIndexMap::<ModuleId, Vec<DependencyEdge>>::default();
+
+            for edge in edges {
+                let next_id = if reverse {
+                    edge.module_id
+                } else {
+                    edge.target_id
+                };
+
+                edges_by_module.entry(next_id).or_default().push(edge);
+            }
+
+            for (next_id, edges) in edges_by_module {
+                let Some(module) = self.modules.get(&next_id) else {
+                    continue;
+                };
+
+                if !options.includes_module(module) || !seen.insert(next_id) {
+                    continue;
+                }
+
+                let mut chain = chains[&current_id].clone();
+                chain.push(next_id);
+
+                results.push(ModuleDependency {
+                    module_id: next_id,
+                    path: module.path.clone(),
+                    depth,
+                    chain: chain.clone(),
+                    edges,
+                });
+
+                chains.insert(next_id, chain);
+                queue.push_back(next_id);
+            }
+        }
+
+        results
+    }
+
+    /// Return the edges that match the options and are traversed from the
+    /// module, which are its imports, or its importers when `reverse` is true.
+    /// Importers are ordered by ID.
+    pub(crate) fn get_dependency_edges(
+        &self,
+        module_id: ModuleId,
+        options: &DependencyQueryOptions,
+        reverse: bool,
+    ) -> Vec<DependencyEdge> {
+        let mut neighbors = self
+            .graph
+            .edges_directed(module_id, if reverse { Incoming } else { Outgoing })
+            .collect::<Vec<_>>();
+
+        if reverse {
+            neighbors.sort_by_key(|(id, _, _)| *id);
+        }
+
+        neighbors
+            .into_iter()
+            .flat_map(|(id, target_id, edges)| {
+                edges.iter().map(move |edge| DependencyEdge {
+                    module_id: id,
+                    source_request: edge.source_request.clone(),
+                    span: edge.span,
+                    target_id,
+                    kind: match edge.kind {
+                        ModuleGraphEdgeKind::Import => Some(edge.import_kind),
+                        ModuleGraphEdgeKind::Export => None,
+                    },
+                    type_only: edge.type_only,
+                })
+            })
+            .filter(|edge| options.includes_edge(edge))
+            .collect()
+    }
+}
@@ -2,6 +2,7 @@ use crate::css::CssModuleError;
 use crate::js::JsModuleError;
 use oxc_resolver::ResolveError;
 use starbase_utils::fs::FsError;
+use starbase_utils::glob::GlobError;
 use starbase_utils::json::JsonError;
 use starbase_utils::yaml::YamlError;
 use std::path::PathBuf;
@@ -18,6 +19,10 @@ pub enum ModuleGraphError {
     #[cfg_attr(feature = "miette", diagnostic(transparent))]
     Fs(#[from] FsError),
 
+    #[error(transparent)]
+    #[cfg_attr(feature = "miette", diagnostic(transparent))]
+    Glob(#[from] GlobError),
+
     #[error(transparent)]
     #[cfg_attr(feature = "miette", diagnostic(transparent))]
     Js(#[from] Box<JsModuleError>),
@@ -38,6 +43,16 @@ pub enum ModuleGraphError {
         error: Box<ResolveError>,
     },
 
+    #[error("Failed to load tsconfig {path}: {error}")]
+    #[cfg_attr(
+        feature = "miette",
+        diagnostic(code(module_graph::tsconfig_load_failed))
+    )]
+    TsConfigLoadFailed {
+        path: PathBuf,
+        error: Box<std::io::Error>,
+    },
+
     #[error("Unsupported file {0}. Not a valid file type or module format.")]
     #[cfg_attr(feature = "miette", diagnostic(code(module_graph::unsupported_file)))]
     UnsupportedFileType(PathBuf),
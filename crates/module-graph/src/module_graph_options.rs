@@ -0,0 +1,276 @@
+use oxc_resolver::ResolveOptions;
+#[cfg(feature = "cache")]
+use std::path::{Path, PathBuf};
+
+pub use oxc_resolver::AliasValue;
+
+#[derive(Clone, Debug)]
+pub struct ModuleGraphOptions {
+    /// Map of specifiers to replacement paths, or `AliasValue::Ignore` to
+    /// treat the module as empty (like `false` in a `browser` field).
+    pub aliases: Vec<(String, Vec<AliasValue>)>,
+
+    /// Respect the `browser` field in `package.json` when resolving
+    /// files within a package.
+    pub browser_field: bool,
+
+    /// Directory to persist extracted imports and exports in, so that
+    /// unchanged files are not parsed again on subsequent runs.
+    #[cfg(feature = "cache")]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Conditions to match against in `exports` and `imports` fields.
+    pub condition_names: Vec<String>,
+
+    /// Expand imports with a non-literal specifier, like
+    /// ``import(`./locales/${lang}.js`)``, into an import of each file that
+    /// matches the pattern, as bundlers do for context modules. A dynamic part
+    /// only matches within a single directory.
+    pub expand_import_patterns: bool,
+
+    /// Treat all packages within `node_modules` as external. They are
+    /// added as leaf nodes and their files are not loaded or parsed.
+    pub external_node_modules: bool,
+
+    /// Package names, or globs of package names, to treat as external.
+    pub external_packages: Vec<String>,
+
+    /// File extensions to try when a specifier has none.
+    pub extensions: Vec<String>,
+
+    /// Fields in `package.json` to use as the package entry point.
+    pub main_fields: Vec<String>,
+
+    /// Treat bare Node.js builtin names, like `fs` or `buffer`, as builtins
+    /// without resolving them. When disabled, they are resolved like any other
+    /// package, so that polyfills in `node_modules` are used. Specifiers with
+    /// a `node:` prefix are always builtins.
+    pub node_builtins: bool,
+
+    /// Resolve symlinks to their real path.
+    pub symlinks: bool,
+
+    /// Collect resolution and parse failures as diagnostics on the importing
+    /// module, instead of aborting the entire graph.
+    pub tolerant: bool,
+
+    /// Resolve specifiers through the `paths` and `baseUrl` compiler options
+    /// of the closest `tsconfig.json` to the importing module.
+    pub tsconfig_paths: bool,
+}
+
+impl Default for ModuleGraphOptions {
+    fn default() -> Self {
+        Self {
+            aliases: vec![],
+            browser_field: false,
+            #[cfg(feature = "cache")]
+            cache_dir: None,
+            condition_names: vec![
+                "import".into(),
+                "module".into(),
+                "require".into(),
+                "node".into(),
+                "default".into(),
+            ],
+            expand_import_patterns: false,
+            external_node_modules: false,
+            external_packages: vec![],
+            extensions: vec![
+                ".ts".into(),
+                ".tsx".into(),
+                ".mts".into(),
+                ".cts".into(),
+                ".mjs".into(),
+                ".cjs".into(),
+                ".js".into(),
+                ".jsx".into(),
+            ],
+            main_fields: vec!["module".into(), "main".into()],
+            node_builtins: true,
+            symlinks: true,
+            tolerant: false,
+            tsconfig_paths: true,
+        }
+    }
+}
+
+impl ModuleGraphOptions {
+    /// Options for code that runs in the browser.
+    pub fn browser() -> Self {
+        Self {
+            browser_field: true,
+            condition_names: vec![
+                "browser".into(),
+                "import".into(),
+                "module".into(),
+                "require".into(),
+                "default".into(),
+            ],
+            main_fields: vec!["browser".into(), "module".into(), "main".into()],
+            node_builtins: false,
+            ..Self::default()
+        }
+    }
+
+    /// Options for code that runs in Node.js.
+    pub fn node() -> Self {
+        Self {
+            condition_names: vec![
+                "node".into(),
+                "import".into(),
+                "require".into(),
+                "default".into(),
+            ],
+            main_fields: vec!["main".into(), "module".into()],
+            ..Self::default()
+        }
+    }
+
+    /// Options for code that runs in React Native.
+    pub fn react_native() -> Self {
+        Self {
+            browser_field: true,
+            condition_names: vec![
+                "react-native".into(),
+                "browser".into(),
+                "import".into(),
+                "require".into(),
+                "default".into(),
+            ],
+            main_fields: vec![
+                "react-native".into(),
+                "browser".into(),
+                "module".into(),
+                "main".into(),
+            ],
+            node_builtins: false,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_alias<K: AsRef<str>, V: AsRef<str>>(mut self, key: K, value: V) -> Self {
+        self.aliases.push((
+            key.as_ref().to_owned(),
+            vec![AliasValue::Path(value.as_ref().to_owned())],
+        ));
+        self
+    }
+
+    pub fn with_ignored_alias<K: AsRef<str>>(mut self, key: K) -> Self {
+        self.aliases
+            .push((key.as_ref().to_owned(), vec![AliasValue::Ignore]));
+        self
+    }
+
+    pub fn with_browser_field(mut self, enabled: bool) -> Self {
+        self.browser_field = enabled;
+        self
+    }
+
+    #[cfg(feature = "cache")]
+    pub fn with_cache_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.cache_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn with_condition_names<I: IntoIterator<Item = V>, V: AsRef<str>>(
+        mut self,
+        names: I,
+    ) -> Self {
+        self.condition_names = names.into_iter().map(|n| n.as_ref().to_owned()).collect();
+        self
+    }
+
+    pub fn with_expand_import_patterns(mut self, enabled: bool) -> Self {
+        self.expand_import_patterns = enabled;
+        self
+    }
+
+    pub fn with_external_node_modules(mut self, enabled: bool) -> Self {
+        self.external_node_modules = enabled;
+        self
+    }
+
+    pub fn with_external_packages<I: IntoIterator<Item = V>, V: AsRef<str>>(
+        mut self,
+        names: I,
+    ) -> Self {
+        self.external_packages = names.into_iter().map(|n| n.as_ref().to_owned()).collect();
+        self
+    }
+
+    pub fn with_extensions<I: IntoIterator<Item = V>, V: AsRef<str>>(
+        mut self,
+        extensions: I,
+    ) -> Self {
+        self.extensions = extensions
+            .into_iter()
+            .map(|ext| {
+                let ext = ext.as_ref();
+
+                if ext.is_empty() || ext.starts_with('.') {
+                    ext.to_owned()
+                } else {
+                    format!(".{ext}")
+                }
+            })
+            .collect();
+        self
+    }
+
+    pub fn with_main_fields<I: IntoIterator<Item = V>, V: AsRef<str>>(mut self, fields: I) -> Self {
+        self.main_fields = fields.into_iter().map(|f| f.as_ref().to_owned()).collect();
+        self
+    }
+
+    pub fn with_node_builtins(mut self, enabled: bool) -> Self {
+        self.node_builtins = enabled;
+        self
+    }
+
+    pub fn with_symlinks(mut self, enabled: bool) -> Self {
+        self.symlinks = enabled;
+        self
+    }
+
+    pub fn with_tolerant(mut self, enabled: bool) -> Self {
+        self.tolerant = enabled;
+        self
+    }
+
+    pub fn with_tsconfig_paths(mut self, enabled: bool) -> Self {
+        self.tsconfig_paths = enabled;
+        self
+    }
+
+    pub fn to_resolve_options(&self) -> ResolveOptions {
+        ResolveOptions {
+            alias: self.aliases.clone(),
+            alias_fields: if self.browser_field {
+                vec![vec!["browser".into()]]
+            } else {
+                vec![]
+            },
+            condition_names: self.condition_names.clone(),
+            extensions: self.extensions.clone(),
+            main_fields: self.main_fields.clone(),
+            symlinks: self.symlinks,
+            ..ResolveOptions::default()
+        }
+    }
+
+    /// Resolve options for type references, which prefer declarations
+    /// over runtime files, and the `types` field over main fields.
+    pub fn to_types_resolve_options(&self) -> ResolveOptions {
+        let mut options = self.to_resolve_options();
+
+        options.condition_names.insert(0, "types".into());
+        options.extensions.insert(0, ".d.ts".into());
+        options
+            .main_fields
+            .splice(0..0, ["types".into(), "typings".into()]);
+
+        options
+    }
+}
@@ -0,0 +1,79 @@
+use crate::module::ModuleId;
+use crate::module_graph::{ModuleGraph, ModuleGraphEdge, ModuleGraphType};
+use petgraph::visit::{Dfs, EdgeFiltered};
+use rustc_hash::FxHashSet;
+
+/// An edge of the module graph: the importing module,
+/// the imported module, and every import between them.
+pub type ModuleGraphEdgeRef<'graph> = (ModuleId, ModuleId, &'graph Vec<ModuleGraphEdge>);
+
+/// A view of the module graph that only includes edges that exist at
+/// runtime, which can be traversed with any of the petgraph visitors.
+pub type RuntimeModuleGraph<'graph> =
+    EdgeFiltered<&'graph ModuleGraphType, fn(ModuleGraphEdgeRef<'_>) -> bool>;
+
+impl ModuleGraph {
+    /// Return a view of the graph without imports and exports that only
+    /// reference types, like `import type`, `import { type A }`, `export type`,
+    /// and `export type *`, as they are erased when compiled.
+    pub fn runtime_graph(&self) -> RuntimeModuleGraph<'_> {
+        EdgeFiltered(
+            &self.graph,
+            is_runtime_edge as fn(ModuleGraphEdgeRef<'_>) -> bool,
+        )
+    }
+
+    /// Return true if the module loads the other module at runtime.
+    pub fn has_runtime_edge(&self, from_id: ModuleId, to_id: ModuleId) -> bool {
+        self.get_edges_between(from_id, to_id)
+            .iter()
+            .any(|edge| !edge.type_only)
+    }
+
+    /// Return all modules that are loaded at runtime, directly or transitively,
+    /// by the entry modules, including the entries themselves. IDs are sorted.
+    pub fn get_runtime_modules(&self, entries: &[ModuleId]) -> Vec<ModuleId> {
+        let graph = self.runtime_graph();
+        let mut ids = FxHashSet::default();
+
+        for entry_id in entries {
+            let mut dfs = Dfs::new(&graph, *entry_id);
+
+            while let Some(module_id) = dfs.next(&graph) {
+                ids.insert(module_id);
+            }
+        }
+
+        let mut ids = ids.into_iter().collect::<Vec<_>>();
+        ids.sort();
+        ids
+    }
+
+    /// Return all modules that are reachable from the entry modules, but only
+    /// through type imports, so they are never loaded at runtime. IDs are sorted.
+    pub fn get_type_only_modules(&self, entries: &[ModuleId]) -> Vec<ModuleId> {
+        let runtime_ids = self
+            .get_runtime_modules(entries)
+            .into_iter()
+            .collect::<FxHashSet<_>>();
+        let mut ids = FxHashSet::default();
+
+        for entry_id in entries {
+            let mut dfs = Dfs::new(&self.graph, *entry_id);
+
+            while let Some(module_id) = dfs.next(&self.graph) {
+                if !runtime_ids.contains(&module_id) {
+                    ids.insert(module_id);
+                }
+            }
+        }
+
+        let mut ids = ids.into_iter().collect::<Vec<_>>();
+        ids.sort();
+        ids
+    }
+}
+
+fn is_runtime_edge((_, _, edges): ModuleGraphEdgeRef<'_>) -> bool {
+    edges.iter().any(|edge| !edge.type_only)
+}
@@ -0,0 +1,305 @@
+use crate::atom::AtomStr;
+use crate::module::{
+    Export, ExportKind, ExportedSymbol, ImportedKind, ImportedSymbol, Module, ModuleId, SourceKind,
+};
+use crate::module_graph::ModuleGraph;
+use oxc::span::Span;
+use oxc::syntax::symbol::SymbolId;
+use rustc_hash::FxHashSet;
+
+/// The declaration that an imported or exported name resolves to.
+#[derive(Debug, PartialEq)]
+pub enum SymbolResolution {
+    /// The name is declared in the module. Modules that can not be
+    /// inspected, like builtins, externals, and media files, are assumed
+    /// to declare every name that is imported from them.
+    Resolved {
+        module_id: ModuleId,
+        name: AtomStr,
+        symbol_id: Option<SymbolId>,
+    },
+
+    /// The name is the namespace object of the module,
+    /// from `import * as name` or `export * as name`.
+    Namespace { module_id: ModuleId },
+
+    /// The name is provided by multiple `export *` declarations,
+    /// so it is not exported at all.
+    Ambiguous { module_ids: Vec<ModuleId> },
+
+    /// The name is not exported by the module.
+    Missing,
+}
+
+impl SymbolResolution {
+    /// Return the module that declares the name, if it has been resolved.
+    pub fn module_id(&self) -> Option<ModuleId> {
+        match self {
+            Self::Resolved { module_id, .. } | Self::Namespace { module_id } => Some(*module_id),
+            _ => None,
+        }
+    }
+}
+
+/// An imported name, linked to the module that declares it.
+#[derive(Debug)]
+pub struct LinkedSymbol {
+    /// The importing module.
+    pub module_id: ModuleId,
+
+    /// The specifier and its location within the importing module.
+    pub source_request: AtomStr,
+    pub span: Span,
+
+    /// The local binding within the importing module.
+    pub name: AtomStr,
+
+    /// The name being imported, which is `default` for default imports,
+    /// and `*` for namespace imports.
+    pub imported_name: AtomStr,
+
+    pub resolution: SymbolResolution,
+}
+
+impl ModuleGraph {
+    /// Link all imported names, in all modules, to their declarations.
+    pub fn link_symbols(&self) -> Vec<LinkedSymbol> {
+        let mut ids = self.modules.keys().copied().collect::<Vec<_>>();
+        ids.sort();
+
+        ids.into_iter()
+            .flat_map(|module_id| self.link_symbols_for(module_id))
+            .collect()
+    }
+
+    /// Link the names imported by the module to their declarations, by following
+    /// `export { name } from`, `export * from`, and `export * as name` chains.
+    pub fn link_symbols_for(&self, module_id: ModuleId) -> Vec<LinkedSymbol> {
+        let mut linked = vec![];
+
+        let Some(module) = self.modules.get(&module_id) else {
+            return linked;
+        };
+
+        for import in &module.imports {
+            for symbol in &import.symbols {
+                linked.push(LinkedSymbol {
+                    module_id,
+                    source_request: import.source_request.clone(),
+                    span: import.span,
+                    name: symbol.name.clone(),
+                    imported_name: get_imported_name(symbol),
+                    resolution: self.resolve_imported_symbol(
+                        import.module_id,
+                        symbol,
+                        &mut FxHashSet::default(),
+                    ),
+                });
+            }
+        }
+
+        linked
+    }
+
+    /// Resolve a name exported by the module to its declaration.
+    pub fn resolve_exported_symbol(&self, module_id: ModuleId, name: &str) -> SymbolResolution {
+        self.resolve_export(module_id, name, &mut FxHashSet::default())
+    }
+
+    pub(crate) fn resolve_imported_symbol(
+        &self,
+        module_id: ModuleId,
+        symbol: &ImportedSymbol,
+        seen: &mut FxHashSet<(ModuleId, AtomStr)>,
+    ) -> SymbolResolution {
+        if symbol.kind.is_namespace() {
+            return SymbolResolution::Namespace { module_id };
+        }
+
+        self.resolve_export(module_id, &get_imported_name(symbol), seen)
+    }
+
+    fn resolve_export(
+        &self,
+        module_id: ModuleId,
+        name: &str,
+        seen: &mut FxHashSet<(ModuleId, AtomStr)>,
+    ) -> SymbolResolution {
+        // Circular re-exports never resolve
+        if !seen.insert((module_id, AtomStr::from(name))) {
+            return SymbolResolution::Missing;
+        }
+
+        let Some(module) = self.modules.get(&module_id) else {
+            return SymbolResolution::Missing;
+        };
+
+        if !is_inspectable(module) {
+            return if matches!(module.source.kind(), SourceKind::Unknown) {
+                SymbolResolution::Missing
+            } else {
+                SymbolResolution::Resolved {
+                    module_id,
+                    name: AtomStr::from(name),
+                    symbol_id: None,
+                }
+            };
+        }
+
+        // Explicitly named exports take precedence over star exports
+        if let Some((export, symbol)) = find_export(module, name) {
+            // export { local as name }
+            // export default local
+            let local_name = &symbol.name;
+
+            return match export.module_id {
+                // export * as name from
+                Some(dep_module_id) if symbol.kind.is_namespace() => SymbolResolution::Namespace {
+                    module_id: dep_module_id,
+                },
+
+                // export { name } from
+                Some(dep_module_id) => self.resolve_export(dep_module_id, local_name, seen),
+
+                // import { name }; export { name }
+                None => match find_import(module, local_name) {
+                    Some((dep_module_id, imported)) => {
+                        self.resolve_imported_symbol(dep_module_id, imported, seen)
+                    }
+                    None => SymbolResolution::Resolved {
+                        module_id,
+                        name: local_name.clone(),
+                        symbol_id: symbol.symbol_id,
+                    },
+                },
+            };
+        }
+
+        let mut candidates = vec![];
+
+        // Star exports never include the default export
+        if name != "default" {
+            for dep_module_id in get_star_exports(module) {
+                match self.resolve_export(dep_module_id, name, seen) {
+                    SymbolResolution::Missing => {}
+                    SymbolResolution::Ambiguous { module_ids } => {
+                        candidates.extend(module_ids.into_iter().map(|id| (id, None)));
+                    }
+                    resolution => {
+                        candidates.push((resolution.module_id().unwrap(), Some(resolution)));
+                    }
+                };
+            }
+        }
+
+        // The same declaration may be reachable through multiple paths
+        candidates.sort_by_key(|candidate| candidate.0);
+        candidates.dedup_by(|a, b| a.0 == b.0 && a.1 == b.1);
+
+        match candidates.len() {
+            0 => {
+                // CommonJS modules can export names dynamically
+                if module
+                    .exports
+                    .iter()
+                    .any(|export| matches!(export.kind, ExportKind::Legacy))
+                {
+                    SymbolResolution::Resolved {
+                        module_id,
+                        name: AtomStr::from(name),
+                        symbol_id: None,
+                    }
+                } else {
+                    SymbolResolution::Missing
+                }
+            }
+            1 if candidates[0].1.is_some() => candidates.remove(0).1.unwrap(),
+            _ => {
+                let mut module_ids = candidates.into_iter().map(|c| c.0).collect::<Vec<_>>();
+                module_ids.dedup();
+
+                SymbolResolution::Ambiguous { module_ids }
+            }
+        }
+    }
+}
+
+/// Return the name of the export within the module, which
+/// is always `default` for default exports.
+pub(crate) fn get_exported_name(symbol: &ExportedSymbol) -> &str {
+    if symbol.kind.is_default() {
+        "default"
+    } else {
+        symbol.exported_name.as_ref().unwrap_or(&symbol.name)
+    }
+}
+
+/// Return the name that a re-export takes from the source module,
+/// which is `*` for `export *` and `export * as name`.
+pub(crate) fn get_re_exported_name(symbol: &ExportedSymbol) -> AtomStr {
+    if symbol.kind.is_namespace() {
+        AtomStr::from("*")
+    } else {
+        symbol.name.clone()
+    }
+}
+
+/// Find the export that declares the name, excluding `export *`.
+pub(crate) fn find_export<'module>(
+    module: &'module Module,
+    name: &str,
+) -> Option<(&'module Export, &'module ExportedSymbol)> {
+    module.exports.iter().find_map(|export| {
+        export
+            .symbols
+            .iter()
+            .find(|symbol| !is_star_export(symbol) && get_exported_name(symbol) == name)
+            .map(|symbol| (export, symbol))
+    })
+}
+
+/// Return the modules that are re-exported with `export *`.
+pub(crate) fn get_star_exports(module: &Module) -> impl Iterator<Item = ModuleId> + '_ {
+    module.exports.iter().filter_map(|export| {
+        if export.symbols.iter().any(is_star_export) {
+            export.module_id
+        } else {
+            None
+        }
+    })
+}
+
+pub(crate) fn get_imported_name(symbol: &ImportedSymbol) -> AtomStr {
+    match symbol.kind {
+        ImportedKind::Default | ImportedKind::DefaultType => AtomStr::from("default"),
+        ImportedKind::Namespace | ImportedKind::NamespaceType => AtomStr::from("*"),
+        _ => symbol
+            .source_name
+            .clone()
+            .unwrap_or_else(|| symbol.name.clone()),
+    }
+}
+
+fn find_import<'module>(
+    module: &'module Module,
+    local_name: &str,
+) -> Option<(ModuleId, &'module ImportedSymbol)> {
+    module.imports.iter().find_map(|import| {
+        import
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name.as_str() == local_name)
+            .map(|symbol| (import.module_id, symbol))
+    })
+}
+
+fn is_star_export(symbol: &ExportedSymbol) -> bool {
+    symbol.kind.is_namespace() && symbol.name.as_str() == "*"
+}
+
+fn is_inspectable(module: &Module) -> bool {
+    matches!(
+        module.source.kind(),
+        SourceKind::JavaScript | SourceKind::Json | SourceKind::Yaml
+    )
+}
@@ -0,0 +1,83 @@
+use crate::module::{ModuleId, SourceKind};
+use crate::module_graph::ModuleGraph;
+use crate::module_graph_error::ModuleGraphError;
+use clean_path::Clean;
+use petgraph::visit::Dfs;
+use rustc_hash::FxHashSet;
+use starbase_utils::glob;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug)]
+pub struct UnreachableFilesOptions {
+    /// Globs of files to ignore, relative to the root.
+    /// Files in `node_modules` are always ignored.
+    pub exclude: Vec<String>,
+
+    /// Globs of files to check, relative to the root.
+    pub include: Vec<String>,
+}
+
+impl Default for UnreachableFilesOptions {
+    fn default() -> Self {
+        Self {
+            exclude: vec![],
+            include: vec!["**/*".into()],
+        }
+    }
+}
+
+impl UnreachableFilesOptions {
+    pub fn with_exclude<I: IntoIterator<Item = V>, V: AsRef<str>>(mut self, globs: I) -> Self {
+        self.exclude = globs.into_iter().map(|g| g.as_ref().to_owned()).collect();
+        self
+    }
+
+    pub fn with_include<I: IntoIterator<Item = V>, V: AsRef<str>>(mut self, globs: I) -> Self {
+        self.include = globs.into_iter().map(|g| g.as_ref().to_owned()).collect();
+        self
+    }
+}
+
+impl ModuleGraph {
+    /// Find all files within the root directory that can be loaded as a
+    /// module, but are never imported (directly or transitively) by the
+    /// entry modules. Paths are absolute and sorted.
+    pub fn get_unreachable_files<P: AsRef<Path>>(
+        &self,
+        root: P,
+        entries: &[ModuleId],
+        options: &UnreachableFilesOptions,
+    ) -> Result<Vec<PathBuf>, ModuleGraphError> {
+        let mut reachable = FxHashSet::default();
+
+        for entry_id in entries {
+            let mut dfs = Dfs::new(&self.graph, *entry_id);
+
+            while let Some(module_id) = dfs.next(&self.graph) {
+                if let Some(module) = self.modules.get(&module_id) {
+                    reachable.insert(module.path.as_path());
+                }
+            }
+        }
+
+        let patterns = options
+            .include
+            .iter()
+            .cloned()
+            .chain(options.exclude.iter().map(|glob| format!("!{glob}")))
+            .collect::<Vec<_>>();
+
+        let mut files = glob::walk_files(root.as_ref(), &patterns)?
+            .into_iter()
+            .map(|path| path.clean())
+            .filter(|path| {
+                SourceKind::from_path(path).is_some() && !reachable.contains(path.as_path())
+            })
+            .collect::<Vec<_>>();
+
+        files.sort();
+        files.dedup();
+
+        Ok(files)
+    }
+}
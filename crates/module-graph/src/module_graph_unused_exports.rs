@@ -0,0 +1,178 @@
+use crate::atom::AtomStr;
+use crate::module::{ExportKind, ExportedSymbol, ImportKind, ModuleId};
+use crate::module_graph::ModuleGraph;
+use crate::module_graph_symbols::{
+    find_export, get_exported_name, get_imported_name, get_re_exported_name, get_star_exports,
+};
+use oxc::span::Span;
+use petgraph::visit::Dfs;
+use rustc_hash::FxHashSet;
+use std::path::PathBuf;
+
+/// An exported symbol that is never imported by another module.
+#[derive(Debug)]
+pub struct UnusedExport {
+    pub module_id: ModuleId,
+    pub path: PathBuf,
+
+    /// Name of the export, which is `default` for default exports.
+    pub name: AtomStr,
+
+    /// Location of the export statement.
+    pub span: Option<Span>,
+
+    /// Whether the export only declares a type.
+    pub type_only: bool,
+}
+
+enum Usage {
+    /// Every export of the module may be used.
+    All(ModuleId),
+
+    /// A single export of the module is used.
+    Name(ModuleId, AtomStr),
+}
+
+impl Usage {
+    // export * from, export * as name from, export { local as name } from
+    fn from_re_export(module_id: ModuleId, symbol: &ExportedSymbol) -> Self {
+        if symbol.kind.is_namespace() {
+            Self::All(module_id)
+        } else {
+            Self::Name(module_id, get_re_exported_name(symbol))
+        }
+    }
+}
+
+impl ModuleGraph {
+    /// Find all exports that are never imported, either directly or through
+    /// re-exports. Exports of the entry modules are always considered used,
+    /// and only imports of modules that the entries reach count as usages.
+    ///
+    /// Namespace imports, `export *` of a fully used module, and dynamic imports
+    /// or requires that are not destructured, mark all exports of the imported
+    /// module as used, as their usage can not be determined statically.
+    pub fn get_unused_exports(&self, entries: &[ModuleId]) -> Vec<UnusedExport> {
+        let mut queue = entries.iter().map(|id| Usage::All(*id)).collect::<Vec<_>>();
+        let mut reachable = FxHashSet::default();
+
+        for entry_id in entries {
+            let mut dfs = Dfs::new(&self.graph, *entry_id);
+
+            while let Some(module_id) = dfs.next(&self.graph) {
+                reachable.insert(module_id);
+            }
+        }
+
+        for module in reachable
+            .into_iter()
+            .filter_map(|module_id| self.modules.get(&module_id))
+        {
+            for import in &module.imports {
+                // import './side-effect'
+                if import.symbols.is_empty() {
+                    if !matches!(import.kind, ImportKind::AsyncStatic) {
+                        queue.push(Usage::All(import.module_id));
+                    }
+
+                    continue;
+                }
+
+                for symbol in &import.symbols {
+                    queue.push(if symbol.kind.is_namespace() {
+                        Usage::All(import.module_id)
+                    } else {
+                        Usage::Name(import.module_id, get_imported_name(symbol))
+                    });
+                }
+            }
+        }
+
+        let mut used_modules = FxHashSet::default();
+        let mut used_names = FxHashSet::default();
+
+        while let Some(usage) = queue.pop() {
+            match usage {
+                Usage::All(module_id) => {
+                    if !used_modules.insert(module_id) {
+                        continue;
+                    }
+
+                    let Some(module) = self.modules.get(&module_id) else {
+                        continue;
+                    };
+
+                    for export in &module.exports {
+                        let Some(dep_module_id) = export.module_id else {
+                            continue;
+                        };
+
+                        for symbol in &export.symbols {
+                            queue.push(Usage::from_re_export(dep_module_id, symbol));
+                        }
+                    }
+                }
+                Usage::Name(module_id, name) => {
+                    if used_modules.contains(&module_id)
+                        || !used_names.insert((module_id, name.clone()))
+                    {
+                        continue;
+                    }
+
+                    let Some(module) = self.modules.get(&module_id) else {
+                        continue;
+                    };
+
+                    if let Some((export, symbol)) = find_export(module, &name) {
+                        if let Some(dep_module_id) = export.module_id {
+                            queue.push(Usage::from_re_export(dep_module_id, symbol));
+                        }
+                    } else if name.as_str() != "default" {
+                        // Any of the star exports may provide the name
+                        for dep_module_id in get_star_exports(module) {
+                            queue.push(Usage::Name(dep_module_id, name.clone()));
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut ids = self.modules.keys().copied().collect::<Vec<_>>();
+        ids.sort();
+
+        let mut unused = vec![];
+
+        for module_id in ids {
+            let module = &self.modules[&module_id];
+
+            if used_modules.contains(&module_id) || module.is_external() || module.is_builtin() {
+                continue;
+            }
+
+            for export in &module.exports {
+                // Keys of data files are not declared by the author
+                if matches!(export.kind, ExportKind::Native) {
+                    continue;
+                }
+
+                for symbol in &export.symbols {
+                    let name = get_exported_name(symbol);
+
+                    if name == "*" || used_names.contains(&(module_id, AtomStr::from(name))) {
+                        continue;
+                    }
+
+                    unused.push(UnusedExport {
+                        module_id,
+                        path: module.path.clone(),
+                        name: AtomStr::from(name),
+                        span: export.span,
+                        type_only: export.type_only || symbol.kind.is_type(),
+                    });
+                }
+            }
+        }
+
+        unused
+    }
+}
@@ -0,0 +1,249 @@
+use crate::module::{ModuleId, SourceKind};
+use crate::module_graph::ModuleGraph;
+use crate::module_graph_error::ModuleGraphError;
+use crate::module_loader::LoadedModule;
+use petgraph::visit::{Dfs, Reversed};
+use petgraph::Direction;
+use rustc_hash::FxHashSet;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct ModuleGraphUpdate {
+    /// Modules that were changed, and all modules that depend on them
+    /// (directly or transitively).
+    pub affected: Vec<ModuleId>,
+
+    /// Modules that were not in the graph, but are now imported.
+    pub added: Vec<ModuleId>,
+
+    /// Modules that were re-read and re-parsed.
+    pub changed: Vec<ModuleId>,
+
+    /// Modules that were deleted from the file system, or are no
+    /// longer reachable from an entry module.
+    pub removed: Vec<ModuleId>,
+}
+
+impl ModuleGraph {
+    /// Mark the modules at the provided paths as changed, so that they are
+    /// re-read and re-parsed on the next [`ModuleGraph::update`]. Paths that
+    /// do not exist in the graph are ignored.
+    pub fn invalidate<I, P>(&mut self, paths: I) -> Vec<ModuleId>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let mut ids = vec![];
+
+        for path in paths {
+            if let Some(module_id) = self.get_module_id(path) {
+                self.invalidated.insert(module_id);
+                ids.push(module_id);
+            }
+        }
+
+        ids
+    }
+
+    /// Re-read and re-parse all invalidated modules, then reconnect their
+    /// edges. Newly imported modules are loaded, while modules that are no
+    /// longer reachable from an entry module are dropped.
+    pub fn update(&mut self) -> Result<ModuleGraphUpdate, ModuleGraphError> {
+        let mut update = ModuleGraphUpdate::default();
+        let mut changed = BTreeSet::default();
+        let mut deleted = BTreeSet::default();
+
+        for module_id in &self.invalidated {
+            let Some(module) = self.modules.get(module_id) else {
+                continue;
+            };
+
+            // Builtins and package boundaries are never loaded from disk
+            if matches!(
+                module.source.kind(),
+                SourceKind::Builtin | SourceKind::External
+            ) {
+                continue;
+            }
+
+            if module.path.exists() {
+                changed.insert(*module_id);
+            } else {
+                deleted.insert(*module_id);
+            }
+        }
+
+        if changed.is_empty() && deleted.is_empty() {
+            self.invalidated.clear();
+
+            return Ok(update);
+        }
+
+        // Re-parse the modules that imported the deleted modules
+        for module_id in &deleted {
+            changed.extend(
+                self.graph
+                    .neighbors_directed(*module_id, Direction::Incoming)
+                    .filter(|dep_id| !deleted.contains(dep_id)),
+            );
+        }
+
+        // Load the changed modules, and any new modules they import,
+        // while assets are not parsed, so are re-read as-is
+        let mut assets = vec![];
+        let mut roots = vec![];
+
+        for module_id in &changed {
+            let module = &self.modules[module_id];
+            let root = (
+                module.path.clone(),
+                self.ids_to_package_jsons.get(module_id).cloned(),
+            );
+
+            if matches!(module.source.kind(), SourceKind::Asset) {
+                assets.push(root);
+            } else {
+                roots.push(root);
+            }
+        }
+
+        let skip = self
+            .paths_to_ids
+            .iter()
+            .filter(|(_, module_id)| !changed.contains(module_id) && !deleted.contains(module_id))
+            .map(|(path, _)| path.to_owned())
+            .collect();
+
+        let loader = self.create_loader()?;
+        let mut loaded = loader.load_all(roots, skip);
+
+        for (path, package_json) in assets {
+            loaded.insert(path.clone(), loader.load_asset(&path, package_json));
+        }
+
+        // Fail before the graph is mutated, so that the invalidated
+        // modules and their edges are kept, and can be updated again
+        if !self.options.tolerant && loaded.values().any(LoadedModule::has_error) {
+            return Err(loaded
+                .into_values()
+                .find_map(LoadedModule::into_error)
+                .unwrap());
+        }
+
+        self.invalidated.clear();
+
+        for module_id in deleted {
+            self.remove_module(module_id);
+            update.removed.push(module_id);
+        }
+
+        let first_new_id = self.next_id;
+
+        // Re-link the changed modules with their new imports and exports
+        for module_id in &changed {
+            let module = &self.modules[module_id];
+            let Some(result) = loaded.remove(&module.path) else {
+                continue;
+            };
+            let query = module.query.clone();
+            let fragment = module.fragment.clone();
+
+            let previous_deps = self
+                .graph
+                .neighbors_directed(*module_id, Direction::Outgoing)
+                .collect::<Vec<_>>();
+
+            for dep_id in previous_deps {
+                self.graph.remove_edge(*module_id, dep_id);
+            }
+
+            if let Err(error) =
+                self.link_loaded_module(*module_id, result, query, fragment, &mut loaded)
+            {
+                // Keep the changed modules invalidated, so that the next
+                // update reconnects any edges that were already removed
+                self.invalidated.extend(changed);
+
+                return Err(error);
+            }
+        }
+
+        update.added = (first_new_id..self.next_id)
+            .filter(|module_id| self.modules.contains_key(module_id))
+            .collect();
+
+        // Drop modules that are no longer imported by anything
+        let mut reachable = FxHashSet::default();
+        let mut dfs = Dfs::empty(&self.graph);
+
+        for entry_id in &self.entries {
+            dfs.move_to(*entry_id);
+
+            while let Some(module_id) = dfs.next(&self.graph) {
+                reachable.insert(module_id);
+            }
+        }
+
+        let orphaned = self
+            .modules
+            .keys()
+            .filter(|module_id| !reachable.contains(module_id))
+            .copied()
+            .collect::<Vec<_>>();
+
+        for module_id in orphaned {
+            self.remove_module(module_id);
+
+            update.added.retain(|id| *id != module_id);
+            update.removed.push(module_id);
+        }
+
+        // Find all modules that depend on the changed modules
+        let mut affected = BTreeSet::default();
+        let reversed = Reversed(&self.graph);
+        let mut dfs = Dfs::empty(reversed);
+
+        for module_id in &changed {
+            if !self.modules.contains_key(module_id) {
+                continue;
+            }
+
+            dfs.move_to(*module_id);
+
+            while let Some(dep_id) = dfs.next(reversed) {
+                affected.insert(dep_id);
+            }
+        }
+
+        update.affected = affected.into_iter().collect();
+        update.changed = changed
+            .into_iter()
+            .filter(|module_id| self.modules.contains_key(module_id))
+            .collect();
+        update.removed.sort();
+
+        Ok(update)
+    }
+
+    fn remove_module(&mut self, module_id: ModuleId) {
+        self.graph.remove_node(module_id);
+
+        // Unresolved modules are keyed separately, and may share
+        // a path with a module that was since created on disk
+        if let Some(module) = self.modules.shift_remove(&module_id) {
+            if self.paths_to_ids.get(&module.path) == Some(&module_id) {
+                self.paths_to_ids.remove(&module.path);
+            }
+
+            if self.unresolved_to_ids.get(&module.path) == Some(&module_id) {
+                self.unresolved_to_ids.remove(&module.path);
+            }
+        }
+
+        self.ids_to_package_jsons.remove(&module_id);
+        self.entries.remove(&module_id);
+        self.failed_modules.remove(&module_id);
+        self.invalidated.remove(&module_id);
+    }
+}
@@ -0,0 +1,397 @@
+use crate::module::{Export, Import, ImportKind, Module, ModuleId, SourceKind};
+use crate::module_graph::ModuleGraph;
+use crate::types::FxIndexMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Default)]
+pub struct ModuleGraphVisualizeOptions {
+    /// Group modules into a cluster (DOT) or subgraph (Mermaid)
+    /// for each package they belong to.
+    pub cluster_packages: bool,
+
+    /// Render each external package as a single node, instead of
+    /// a node for every module within it.
+    pub collapse_externals: bool,
+
+    /// Fill nodes with a colour based on their source kind.
+    pub color_by_kind: bool,
+
+    /// Label modules relative to this directory, instead of by absolute path.
+    pub root: Option<PathBuf>,
+
+    /// Draw edges differently for static imports, dynamic imports,
+    /// requires, and type-only imports.
+    pub style_edges: bool,
+}
+
+impl ModuleGraphVisualizeOptions {
+    pub fn with_cluster_packages(mut self, enabled: bool) -> Self {
+        self.cluster_packages = enabled;
+        self
+    }
+
+    pub fn with_collapse_externals(mut self, enabled: bool) -> Self {
+        self.collapse_externals = enabled;
+        self
+    }
+
+    pub fn with_color_by_kind(mut self, enabled: bool) -> Self {
+        self.color_by_kind = enabled;
+        self
+    }
+
+    pub fn with_root<P: AsRef<Path>>(mut self, root: P) -> Self {
+        self.root = Some(root.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn with_style_edges(mut self, enabled: bool) -> Self {
+        self.style_edges = enabled;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+enum EdgeStyle {
+    Static,
+    Require,
+    Dynamic,
+    TypeOnly,
+}
+
+impl EdgeStyle {
+    fn from_import(import: &Import) -> Self {
+        if import.is_type_only() {
+            return Self::TypeOnly;
+        }
+
+        match import.kind {
+            ImportKind::AsyncStatic => Self::Static,
+            ImportKind::AsyncDynamic | ImportKind::AssetUrl | ImportKind::Worker => Self::Dynamic,
+            ImportKind::SyncStatic => Self::Require,
+            ImportKind::ReferencePath | ImportKind::ReferenceTypes | ImportKind::ReferenceLib => {
+                Self::TypeOnly
+            }
+        }
+    }
+
+    fn from_export(export: &Export) -> Self {
+        if export.is_type_only() {
+            Self::TypeOnly
+        } else {
+            Self::Static
+        }
+    }
+}
+
+struct VisualNode {
+    id: String,
+    label: String,
+    kind: SourceKind,
+    package_name: Option<String>,
+}
+
+/// A renderer agnostic representation of the graph,
+/// with externals already collapsed.
+struct VisualGraph {
+    nodes: Vec<VisualNode>,
+    edges: Vec<(String, String, EdgeStyle)>,
+}
+
+impl VisualGraph {
+    /// Return nodes grouped by package, with ungrouped nodes last.
+    fn get_clusters(&self, enabled: bool) -> FxIndexMap<Option<&str>, Vec<&VisualNode>> {
+        let mut clusters = FxIndexMap::<_, Vec<_>>::default();
+
+        for node in &self.nodes {
+            let package_name = if enabled {
+                node.package_name.as_deref()
+            } else {
+                None
+            };
+
+            clusters.entry(package_name).or_default().push(node);
+        }
+
+        if let Some(nodes) = clusters.shift_remove(&None) {
+            clusters.insert(None, nodes);
+        }
+
+        clusters
+    }
+}
+
+impl ModuleGraph {
+    /// Render the graph in the Graphviz DOT language.
+    pub fn to_dot(&self, options: &ModuleGraphVisualizeOptions) -> String {
+        let graph = self.create_visual_graph(options);
+        let mut out = String::from("digraph {\n");
+
+        out.push_str(if options.color_by_kind {
+            "    node [shape=box, style=filled];\n"
+        } else {
+            "    node [shape=box];\n"
+        });
+
+        for (index, (package_name, nodes)) in graph
+            .get_clusters(options.cluster_packages)
+            .into_iter()
+            .enumerate()
+        {
+            let indent = if let Some(name) = package_name {
+                let _ = writeln!(out, "    subgraph cluster_{index} {{");
+                let _ = writeln!(out, "        label=\"{}\";", escape_dot(name));
+                "        "
+            } else {
+                "    "
+            };
+
+            for node in nodes {
+                let _ = write!(
+                    out,
+                    "{indent}{} [label=\"{}\"",
+                    node.id,
+                    escape_dot(&node.label)
+                );
+
+                if options.color_by_kind {
+                    let _ = write!(out, ", fillcolor=\"{}\"", get_kind_style(&node.kind).1);
+                }
+
+                out.push_str("];\n");
+            }
+
+            if package_name.is_some() {
+                out.push_str("    }\n");
+            }
+        }
+
+        for (from, to, style) in &graph.edges {
+            let _ = write!(out, "    {from} -> {to}");
+
+            if options.style_edges {
+                out.push_str(match style {
+                    EdgeStyle::Static => "",
+                    EdgeStyle::Require => " [style=bold]",
+                    EdgeStyle::Dynamic => " [style=dashed]",
+                    EdgeStyle::TypeOnly => " [style=dotted]",
+                });
+            }
+
+            out.push_str(";\n");
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Render the graph as a Mermaid flowchart.
+    pub fn to_mermaid(&self, options: &ModuleGraphVisualizeOptions) -> String {
+        let graph = self.create_visual_graph(options);
+        let mut out = String::from("flowchart LR\n");
+
+        for (index, (package_name, nodes)) in graph
+            .get_clusters(options.cluster_packages)
+            .into_iter()
+            .enumerate()
+        {
+            let indent = if let Some(name) = package_name {
+                let _ = writeln!(out, "    subgraph c{index} [\"{}\"]", escape_mermaid(name));
+                "        "
+            } else {
+                "    "
+            };
+
+            for node in nodes {
+                let _ = writeln!(
+                    out,
+                    "{indent}{}[\"{}\"]",
+                    node.id,
+                    escape_mermaid(&node.label)
+                );
+            }
+
+            if package_name.is_some() {
+                out.push_str("    end\n");
+            }
+        }
+
+        for (from, to, style) in &graph.edges {
+            let arrow = if options.style_edges {
+                match style {
+                    EdgeStyle::Static => "-->",
+                    EdgeStyle::Require => "==>",
+                    EdgeStyle::Dynamic => "-.->",
+                    EdgeStyle::TypeOnly => "-. type .->",
+                }
+            } else {
+                "-->"
+            };
+
+            let _ = writeln!(out, "    {from} {arrow} {to}");
+        }
+
+        if options.color_by_kind {
+            let mut classes = FxIndexMap::<&str, (&str, Vec<&str>)>::default();
+
+            for node in &graph.nodes {
+                let (name, color) = get_kind_style(&node.kind);
+
+                classes
+                    .entry(name)
+                    .or_insert_with(|| (color, vec![]))
+                    .1
+                    .push(&node.id);
+            }
+
+            classes.sort_keys();
+
+            for (name, (color, ids)) in classes {
+                let _ = writeln!(out, "    classDef {name} fill:{color}");
+                let _ = writeln!(out, "    class {} {name}", ids.join(","));
+            }
+        }
+
+        out
+    }
+
+    fn create_visual_graph(&self, options: &ModuleGraphVisualizeOptions) -> VisualGraph {
+        let mut ids = self.modules.keys().copied().collect::<Vec<_>>();
+        ids.sort();
+
+        let mut nodes = vec![];
+        let mut node_ids = FxHashMap::<ModuleId, String>::default();
+        let mut packages = FxIndexMap::<String, String>::default();
+
+        for module_id in &ids {
+            let module = &self.modules[module_id];
+
+            if options.collapse_externals && module.is_external() {
+                let package_name = module
+                    .package_name
+                    .clone()
+                    .unwrap_or_else(|| get_label(module, options));
+                let next_id = format!("p{}", packages.len());
+
+                let node_id = packages
+                    .entry(package_name.clone())
+                    .or_insert_with(|| {
+                        nodes.push(VisualNode {
+                            id: next_id.clone(),
+                            label: package_name,
+                            kind: SourceKind::External,
+                            package_name: None,
+                        });
+
+                        next_id
+                    })
+                    .clone();
+
+                node_ids.insert(*module_id, node_id);
+                continue;
+            }
+
+            let node_id = format!("m{module_id}");
+
+            nodes.push(VisualNode {
+                id: node_id.clone(),
+                label: get_label(module, options),
+                kind: module.source.kind(),
+                package_name: module.package_name.clone(),
+            });
+
+            node_ids.insert(*module_id, node_id);
+        }
+
+        let mut edges = vec![];
+
+        for module_id in &ids {
+            let module = &self.modules[module_id];
+
+            let imports = module
+                .imports
+                .iter()
+                .map(|import| (import.module_id, EdgeStyle::from_import(import)));
+
+            let exports = module
+                .exports
+                .iter()
+                .filter_map(|export| Some((export.module_id?, EdgeStyle::from_export(export))));
+
+            for (dep_module_id, style) in imports.chain(exports) {
+                let (Some(from), Some(to)) =
+                    (node_ids.get(module_id), node_ids.get(&dep_module_id))
+                else {
+                    continue;
+                };
+
+                // Modules within a collapsed package
+                if from == to && *module_id != dep_module_id {
+                    continue;
+                }
+
+                edges.push((
+                    from.to_owned(),
+                    to.to_owned(),
+                    if options.style_edges {
+                        style
+                    } else {
+                        EdgeStyle::Static
+                    },
+                ));
+            }
+        }
+
+        // Keep the first occurrence of each edge, in module order
+        let mut seen = FxHashSet::default();
+        edges.retain(|edge| seen.insert(edge.clone()));
+
+        VisualGraph { nodes, edges }
+    }
+}
+
+fn get_label(module: &Module, options: &ModuleGraphVisualizeOptions) -> String {
+    if let Some(package_name) = module
+        .package_name
+        .as_ref()
+        .filter(|_| matches!(module.source.kind(), SourceKind::External))
+    {
+        return package_name.to_owned();
+    }
+
+    let path = options
+        .root
+        .as_ref()
+        .and_then(|root| module.path.strip_prefix(root).ok())
+        .unwrap_or(&module.path);
+
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Return the class name and fill colour for the kind of source.
+fn get_kind_style(kind: &SourceKind) -> (&'static str, &'static str) {
+    match kind {
+        SourceKind::Unknown => ("unknown", "#ffffff"),
+        SourceKind::Asset => ("asset", "#fdebd0"),
+        SourceKind::Audio => ("audio", "#d7bde2"),
+        SourceKind::Builtin => ("builtin", "#a9dfbf"),
+        SourceKind::Css => ("css", "#aed6f1"),
+        SourceKind::External => ("external", "#d5d8dc"),
+        SourceKind::Image => ("image", "#f5cba7"),
+        SourceKind::JavaScript => ("javascript", "#f9e79f"),
+        SourceKind::Json => ("json", "#f5b7b1"),
+        SourceKind::Text => ("text", "#e5e8e8"),
+        SourceKind::Video => ("video", "#d2b4de"),
+        SourceKind::Yaml => ("yaml", "#fad7a0"),
+    }
+}
+
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(value: &str) -> String {
+    value.replace('"', "#quot;")
+}
@@ -0,0 +1,681 @@
+use crate::atom::AtomStr;
+use crate::builtin::BuiltinModule;
+use crate::module::*;
+use crate::module_graph_error::ModuleGraphError;
+use crate::module_graph_options::ModuleGraphOptions;
+use crate::tsconfig_paths::TsConfigPaths;
+use clean_path::Clean;
+use nodejs_package_json::PackageJson;
+use oxc_resolver::{PackageJson as ResolvedPackageJson, Resolution, Resolver};
+use regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+use starbase_utils::glob::{self, GlobSet};
+use starbase_utils::json;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// The target of an import or export specifier.
+pub(crate) enum ResolvedDependency {
+    /// A module provided by the runtime, without the `node:` prefix.
+    Builtin(String),
+
+    /// A package boundary that should not be traversed.
+    External {
+        package_json: Arc<PackageJson>,
+        root: PathBuf,
+    },
+
+    /// A file that should be loaded into the graph.
+    Module(Resolution),
+
+    /// A file referenced by URL that can't be parsed, like a font
+    /// or WebAssembly, which is loaded into the graph as-is.
+    Asset(Resolution),
+
+    /// A specifier without a target, like a non-literal pattern
+    /// that was not expanded, or a TypeScript lib reference.
+    Unlinked,
+}
+
+/// A module that has been loaded and parsed, but not yet linked into the graph.
+pub(crate) struct LoadedModule {
+    pub path: PathBuf,
+    pub package_json: Option<Arc<ResolvedPackageJson>>,
+    pub module: Result<Module, ModuleGraphError>,
+    pub import_resolutions: Vec<Result<ResolvedDependency, ModuleGraphError>>,
+    pub export_resolutions: Vec<Option<Result<ResolvedDependency, ModuleGraphError>>>,
+}
+
+// Type references that fail to resolve are reported
+// as diagnostics, so they never fail the load
+impl LoadedModule {
+    pub fn has_error(&self) -> bool {
+        let Ok(module) = &self.module else {
+            return true;
+        };
+
+        module
+            .imports
+            .iter()
+            .zip(&self.import_resolutions)
+            .any(|(import, resolution)| {
+                import.kind != ImportKind::ReferenceTypes && resolution.is_err()
+            })
+            || self.export_resolutions.iter().flatten().any(Result::is_err)
+    }
+
+    pub fn into_error(self) -> Option<ModuleGraphError> {
+        let module = match self.module {
+            Ok(module) => module,
+            Err(error) => return Some(error),
+        };
+
+        module
+            .imports
+            .iter()
+            .zip(self.import_resolutions)
+            .filter(|(import, _)| import.kind != ImportKind::ReferenceTypes)
+            .map(|(_, resolution)| resolution)
+            .chain(self.export_resolutions.into_iter().flatten())
+            .find_map(Result::err)
+    }
+}
+
+/// Loads, parses, and resolves modules across a thread pool. Shared caches
+/// are borrowed from the graph and guarded so that workers can populate them.
+pub(crate) struct ModuleLoader<'graph> {
+    #[cfg(feature = "cache")]
+    pub cache: Option<crate::module_cache::ModuleCache>,
+    pub external_packages: GlobSet<'graph>,
+    pub options: &'graph ModuleGraphOptions,
+    pub resolver: &'graph Resolver,
+    pub types_resolver: Resolver,
+    pub packages: Mutex<&'graph mut FxHashMap<PathBuf, Arc<PackageJson>>>,
+    pub tsconfigs: Mutex<&'graph mut FxHashMap<PathBuf, Arc<TsConfigPaths>>>,
+    pub dirs_to_tsconfigs: Mutex<&'graph mut FxHashMap<PathBuf, Option<PathBuf>>>,
+    pub tsconfig_errors: Mutex<&'graph mut FxHashMap<PathBuf, Arc<ModuleGraphError>>>,
+}
+
+impl<'graph> ModuleLoader<'graph> {
+    /// Load the modules at the provided paths, and all of their dependencies
+    /// recursively. Paths in `skip` are assumed to already be loaded.
+    pub fn load_all(
+        &self,
+        roots: Vec<(PathBuf, Option<Arc<ResolvedPackageJson>>)>,
+        skip: FxHashSet<PathBuf>,
+    ) -> FxHashMap<PathBuf, LoadedModule> {
+        let seen = Mutex::new(skip);
+        let loaded = Mutex::new(FxHashMap::default());
+
+        rayon::scope(|scope| {
+            for (path, package_json) in roots {
+                self.load_in_scope(scope, &seen, &loaded, path, package_json, false);
+            }
+        });
+
+        loaded.into_inner().unwrap()
+    }
+
+    fn load_in_scope<'scope>(
+        &'scope self,
+        scope: &rayon::Scope<'scope>,
+        seen: &'scope Mutex<FxHashSet<PathBuf>>,
+        loaded: &'scope Mutex<FxHashMap<PathBuf, LoadedModule>>,
+        path: PathBuf,
+        package_json: Option<Arc<ResolvedPackageJson>>,
+        asset: bool,
+    ) {
+        if !seen.lock().unwrap().insert(path.clone()) {
+            return;
+        }
+
+        scope.spawn(move |scope| {
+            let result = if asset {
+                self.load_asset(&path, package_json)
+            } else {
+                self.load(&path, package_json)
+            };
+
+            let resolutions = result
+                .import_resolutions
+                .iter()
+                .chain(result.export_resolutions.iter().flatten())
+                .flatten();
+
+            for resolution in resolutions {
+                let (resolved_path, asset) = match resolution {
+                    ResolvedDependency::Module(resolved_path) => (resolved_path, false),
+                    ResolvedDependency::Asset(resolved_path) => (resolved_path, true),
+                    _ => continue,
+                };
+
+                self.load_in_scope(
+                    scope,
+                    seen,
+                    loaded,
+                    resolved_path.path().clean(),
+                    resolved_path.package_json().map(Arc::clone),
+                    asset,
+                );
+            }
+
+            loaded.lock().unwrap().insert(path, result);
+        });
+    }
+
+    /// Load and parse a single module, then resolve all of its
+    /// import and export specifiers.
+    pub fn load(
+        &self,
+        path: &Path,
+        package_json: Option<Arc<ResolvedPackageJson>>,
+    ) -> LoadedModule {
+        let mut module = self.load_module(path, package_json.clone());
+        let mut import_resolutions = vec![];
+        let mut export_resolutions = vec![];
+
+        module = module.and_then(|mut module| {
+            self.expand_import_patterns(path, &mut module)?;
+
+            Ok(module)
+        });
+
+        if let Ok(module) = &module {
+            let parent_dir = path.parent().unwrap();
+
+            for import in &module.imports {
+                import_resolutions.push(
+                    if import.pattern.as_ref() == Some(&import.source_request)
+                        || import.kind == ImportKind::ReferenceLib
+                    {
+                        Ok(ResolvedDependency::Unlinked)
+                    } else if import.kind == ImportKind::ReferenceTypes {
+                        self.resolve_type_reference(parent_dir, &import.source_request)
+                    } else if import.kind == ImportKind::AssetUrl {
+                        self.resolve_asset(parent_dir, &import.source_request)
+                    } else {
+                        self.resolve_dependency(parent_dir, &import.source_request)
+                    },
+                );
+            }
+
+            for export in &module.exports {
+                export_resolutions.push(
+                    export
+                        .source
+                        .as_ref()
+                        .map(|source| self.resolve_dependency(parent_dir, source)),
+                );
+            }
+        }
+
+        LoadedModule {
+            path: path.to_owned(),
+            package_json,
+            module,
+            import_resolutions,
+            export_resolutions,
+        }
+    }
+
+    /// Load a file that is referenced by URL as-is, without parsing it.
+    pub fn load_asset(
+        &self,
+        path: &Path,
+        package_json: Option<Arc<ResolvedPackageJson>>,
+    ) -> LoadedModule {
+        let mut module = Module::new(path);
+
+        let module = package_json
+            .as_ref()
+            .map(|json| self.load_package_json(&json.realpath))
+            .transpose()
+            .and_then(|package_json| {
+                module.load_asset(package_json)?;
+
+                Ok(module)
+            });
+
+        LoadedModule {
+            path: path.to_owned(),
+            package_json,
+            module,
+            import_resolutions: vec![],
+            export_resolutions: vec![],
+        }
+    }
+
+    pub fn load_module(
+        &self,
+        path: &Path,
+        package_json: Option<Arc<ResolvedPackageJson>>,
+    ) -> Result<Module, ModuleGraphError> {
+        // Load the package.json before the module
+        let package_json = if let Some(json) = package_json {
+            Some(self.load_package_json(&json.realpath)?)
+        } else {
+            None
+        };
+
+        let mut module = Module::new(path);
+
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.cache {
+            cache.load_and_parse_source(&mut module, package_json)?;
+
+            return Ok(module);
+        }
+
+        module.load_and_parse_source(package_json)?;
+
+        Ok(module)
+    }
+
+    /// Replace each import of a glob, and each import with a relative pattern
+    /// like `./locales/*.js` when enabled, with an import of every file that
+    /// matches it. Imports without any matches are kept as-is.
+    fn expand_import_patterns(
+        &self,
+        path: &Path,
+        module: &mut Module,
+    ) -> Result<(), ModuleGraphError> {
+        let parent_dir = path.parent().unwrap();
+        let mut imports = vec![];
+
+        for import in mem::take(&mut module.imports) {
+            let mut files = if let Some(glob) = &import.glob {
+                match_import_glob(parent_dir, glob)?
+            } else if let Some(pattern) = import
+                .pattern
+                .as_ref()
+                .filter(|_| self.options.expand_import_patterns)
+            {
+                match_import_pattern(parent_dir, pattern)?
+            } else {
+                vec![]
+            };
+
+            files.retain(|(file, _)| file != path);
+            files.sort();
+
+            if files.is_empty() {
+                imports.push(import);
+                continue;
+            }
+
+            for (_, specifier) in files {
+                imports.push(Import {
+                    attributes: import.attributes.clone(),
+                    glob: import.glob.clone(),
+                    kind: import.kind,
+                    module_id: 0,
+                    pattern: import.pattern.clone(),
+                    source_request: AtomStr::from(&specifier),
+                    span: import.span,
+                    symbols: import.symbols.clone(),
+                    type_only: import.type_only,
+                });
+            }
+        }
+
+        module.imports = imports;
+
+        Ok(())
+    }
+
+    /// Files referenced by URL are emitted as-is, so any file type is valid.
+    /// Types that can't be parsed are linked as opaque assets.
+    pub fn resolve_asset(
+        &self,
+        parent_dir: &Path,
+        specifier: &str,
+    ) -> Result<ResolvedDependency, ModuleGraphError> {
+        Ok(match self.resolve_dependency(parent_dir, specifier)? {
+            ResolvedDependency::Module(resolved_path)
+                if SourceKind::from_path(resolved_path.path()).is_none() =>
+            {
+                ResolvedDependency::Asset(resolved_path)
+            }
+            dependency => dependency,
+        })
+    }
+
+    pub fn resolve_dependency(
+        &self,
+        parent_dir: &Path,
+        specifier: &str,
+    ) -> Result<ResolvedDependency, ModuleGraphError> {
+        // Aliases may replace builtins with polyfills, and bare names
+        // are only builtins when targeting Node.js
+        if let Some(name) = BuiltinModule::parse_specifier(specifier) {
+            if (self.options.node_builtins || specifier.starts_with("node:"))
+                && !self
+                    .options
+                    .aliases
+                    .iter()
+                    .any(|(key, _)| key.trim_end_matches('$') == specifier)
+            {
+                return Ok(ResolvedDependency::Builtin(name.to_owned()));
+            }
+        }
+
+        let resolved_path = self.resolve(parent_dir, specifier)?;
+
+        self.to_dependency(parent_dir, resolved_path)
+    }
+
+    fn to_dependency(
+        &self,
+        parent_dir: &Path,
+        resolved_path: Resolution,
+    ) -> Result<ResolvedDependency, ModuleGraphError> {
+        if let Some(package_json) = resolved_path.package_json() {
+            let root = package_json.realpath.parent().unwrap();
+
+            // Files within the importing package are never external
+            if !parent_dir.starts_with(root)
+                && self.is_external_package(resolved_path.path(), package_json.name.as_deref())
+            {
+                return Ok(ResolvedDependency::External {
+                    package_json: self.load_package_json(&package_json.realpath)?,
+                    root: root.clean(),
+                });
+            }
+        }
+
+        Ok(ResolvedDependency::Module(resolved_path))
+    }
+
+    /// Resolve the name of a `/// <reference types="..." />` directive to the
+    /// declarations of its `@types/*` package, or of the package itself,
+    /// using the `types` export condition for subpaths like `vite/client`.
+    pub fn resolve_type_reference(
+        &self,
+        parent_dir: &Path,
+        name: &str,
+    ) -> Result<ResolvedDependency, ModuleGraphError> {
+        // @scope/name -> @types/scope__name
+        let types_name = match name.strip_prefix('@') {
+            Some(scoped_name) => format!("@types/{}", scoped_name.replacen('/', "__", 1)),
+            None => format!("@types/{name}"),
+        };
+
+        let resolved_path = self
+            .types_resolver
+            .resolve(parent_dir, &types_name)
+            .or_else(|_| self.types_resolver.resolve(parent_dir, name))
+            .map_err(|error| ModuleGraphError::ResolveFailed {
+                dir: parent_dir.to_owned(),
+                specifier: name.to_owned(),
+                error: Box::new(error),
+            })?;
+
+        self.to_dependency(parent_dir, resolved_path)
+    }
+
+    fn is_external_package(&self, path: &Path, package_name: Option<&str>) -> bool {
+        if self.options.external_node_modules
+            && path
+                .components()
+                .any(|comp| comp.as_os_str() == "node_modules")
+        {
+            return true;
+        }
+
+        package_name.is_some_and(|name| self.external_packages.matches(name))
+    }
+
+    pub fn resolve(
+        &self,
+        parent_dir: &Path,
+        specifier: &str,
+    ) -> Result<Resolution, ModuleGraphError> {
+        // Aliases from tsconfig take precedence over node modules,
+        // but relative and absolute specifiers are never aliased
+        if self.options.tsconfig_paths
+            && !specifier.starts_with('.')
+            && !Path::new(specifier).is_absolute()
+        {
+            if let Some(tsconfig) = self.load_tsconfig_paths_for(parent_dir) {
+                for candidate in tsconfig.get_candidates(specifier) {
+                    if let Ok(resolved_path) = self
+                        .resolver
+                        .resolve(parent_dir, &candidate.to_string_lossy())
+                    {
+                        return Ok(resolved_path);
+                    }
+                }
+            }
+        }
+
+        self.resolver
+            .resolve(parent_dir, specifier)
+            .map_err(|error| ModuleGraphError::ResolveFailed {
+                dir: parent_dir.to_owned(),
+                specifier: specifier.to_owned(),
+                error: Box::new(error),
+            })
+    }
+
+    /// Load the paths from the closest tsconfig to the provided directory.
+    /// A tsconfig that fails to load is treated as having no paths, and
+    /// the failure is recorded so that it can be reported as a diagnostic.
+    pub fn load_tsconfig_paths_for(&self, dir: &Path) -> Option<Arc<TsConfigPaths>> {
+        // Third-party packages are compiled ahead of time,
+        // so their tsconfig should not apply
+        if dir
+            .components()
+            .any(|comp| comp.as_os_str() == "node_modules")
+        {
+            return None;
+        }
+
+        let tsconfig_path = {
+            let mut dirs_to_tsconfigs = self.dirs_to_tsconfigs.lock().unwrap();
+
+            match dirs_to_tsconfigs.get(dir) {
+                Some(path) => path.clone(),
+                None => {
+                    let path = TsConfigPaths::find_tsconfig(dir);
+
+                    dirs_to_tsconfigs.insert(dir.to_owned(), path.clone());
+
+                    path
+                }
+            }
+        };
+
+        let tsconfig_path = tsconfig_path?;
+
+        if let Some(paths) = self.tsconfigs.lock().unwrap().get(&tsconfig_path) {
+            return (!paths.is_empty()).then(|| Arc::clone(paths));
+        }
+
+        let paths = Arc::new(match TsConfigPaths::load(&tsconfig_path) {
+            Ok(paths) => paths,
+            Err(error) => {
+                self.tsconfig_errors.lock().unwrap().insert(
+                    tsconfig_path.clone(),
+                    Arc::new(ModuleGraphError::TsConfigLoadFailed {
+                        path: tsconfig_path.clone(),
+                        error: Box::new(error),
+                    }),
+                );
+
+                TsConfigPaths::default()
+            }
+        });
+
+        self.tsconfigs
+            .lock()
+            .unwrap()
+            .insert(tsconfig_path, Arc::clone(&paths));
+
+        (!paths.is_empty()).then_some(paths)
+    }
+
+    pub fn load_package_json(&self, path: &Path) -> Result<Arc<PackageJson>, ModuleGraphError> {
+        if let Some(json) = self.packages.lock().unwrap().get(path) {
+            return Ok(Arc::clone(json));
+        }
+
+        let json: PackageJson = json::read_file(path)?;
+        let data = Arc::new(json);
+
+        self.packages
+            .lock()
+            .unwrap()
+            .insert(path.to_path_buf(), Arc::clone(&data));
+
+        Ok(data)
+    }
+}
+
+/// Return the files, and their specifiers, that match a relative
+/// pattern like `./locales/*.js`.
+fn match_import_pattern(
+    parent_dir: &Path,
+    pattern: &str,
+) -> Result<Vec<(PathBuf, String)>, ModuleGraphError> {
+    if !pattern.starts_with("./") && !pattern.starts_with("../") {
+        return Ok(vec![]);
+    }
+
+    // Split at the last static directory: `./locales` and `*.js`
+    let glob_index = pattern.find('*').unwrap_or(pattern.len());
+    let dir_index = pattern[..glob_index].rfind('/').unwrap();
+    let (prefix, glob) = (&pattern[..dir_index], &pattern[dir_index + 1..]);
+
+    walk_import_dir(parent_dir, prefix, &[glob])
+}
+
+/// Return the files, and their specifiers, that match an `import.meta.glob()`
+/// or `require.context()` call. Only relative patterns and directories are
+/// supported. Regular expressions that can't be compiled, like those with
+/// lookarounds, match nothing.
+fn match_import_glob(
+    parent_dir: &Path,
+    glob: &ImportGlob,
+) -> Result<Vec<(PathBuf, String)>, ModuleGraphError> {
+    match glob {
+        ImportGlob::Patterns(patterns) => {
+            // Walk from the highest directory that the patterns reference,
+            // so that `./a/*.js` and `../b/*.js` can be combined
+            let mut depth = 0;
+            let mut globs = vec![];
+
+            for pattern in patterns {
+                let (negated, pattern) = match pattern.strip_prefix('!') {
+                    Some(pattern) => ("!", pattern),
+                    None => ("", pattern.as_str()),
+                };
+
+                let mut pattern_depth = 0;
+                let mut rel_pattern = pattern;
+
+                if let Some(rest) = rel_pattern.strip_prefix("./") {
+                    rel_pattern = rest;
+                } else if rel_pattern.starts_with("../") {
+                    while let Some(rest) = rel_pattern.strip_prefix("../") {
+                        rel_pattern = rest;
+                        pattern_depth += 1;
+                    }
+                } else {
+                    continue;
+                }
+
+                depth = depth.max(pattern_depth);
+                globs.push((negated, pattern_depth, rel_pattern));
+            }
+
+            let Some(root) = parent_dir.ancestors().nth(depth) else {
+                return Ok(vec![]);
+            };
+
+            // Then rewrite each pattern to be relative to that directory
+            let globs = globs
+                .into_iter()
+                .map(|(negated, pattern_depth, rel_pattern)| {
+                    let dir = parent_dir.ancestors().nth(pattern_depth).unwrap();
+                    let dir = dir.strip_prefix(root).unwrap().to_string_lossy();
+
+                    if dir.is_empty() {
+                        format!("{negated}{rel_pattern}")
+                    } else {
+                        format!("{negated}{}/{rel_pattern}", dir.replace('\\', "/"))
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            if !globs.iter().any(|glob| !glob.starts_with('!')) {
+                return Ok(vec![]);
+            }
+
+            let prefix = if depth == 0 {
+                ".".to_owned()
+            } else {
+                vec![".."; depth].join("/")
+            };
+
+            walk_import_dir(parent_dir, &prefix, &globs)
+        }
+        ImportGlob::Context {
+            directory,
+            recursive,
+            regex,
+        } => {
+            if !directory.starts_with('.') {
+                return Ok(vec![]);
+            }
+
+            let Ok(regex) = Regex::new(regex) else {
+                return Ok(vec![]);
+            };
+
+            let prefix = directory.trim_end_matches('/');
+            let glob = if *recursive { "**/*" } else { "*" };
+            let mut files = walk_import_dir(parent_dir, prefix, &[glob])?;
+
+            // Keys are relative to the directory, like `./home.js`
+            files.retain(|(_, specifier)| {
+                regex.is_match(&format!(".{}", &specifier[prefix.len()..]))
+            });
+
+            Ok(files)
+        }
+    }
+}
+
+/// Walk the directory relative to the parent, and return each file that
+/// matches the globs, with a specifier of the directory joined with the file.
+/// Files that can't be loaded as a module, like `README.md`, are skipped.
+fn walk_import_dir<V: AsRef<str>>(
+    parent_dir: &Path,
+    prefix: &str,
+    globs: &[V],
+) -> Result<Vec<(PathBuf, String)>, ModuleGraphError> {
+    let dir = parent_dir.join(prefix).clean();
+
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let mut files = vec![];
+
+    for file in glob::walk_files(&dir, globs)? {
+        if SourceKind::from_path(&file).is_none() {
+            continue;
+        }
+
+        let Ok(rel_file) = file.strip_prefix(&dir) else {
+            continue;
+        };
+
+        let specifier = format!("{prefix}/{}", rel_file.to_string_lossy().replace('\\', "/"));
+
+        files.push((file, specifier));
+    }
+
+    Ok(files)
+}
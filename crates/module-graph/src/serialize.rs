@@ -0,0 +1,129 @@
+use crate::module::ModuleSource;
+use crate::module_graph::{ModuleGraph, ModuleGraphEdge};
+use crate::module_graph_error::ModuleGraphError;
+use oxc::span::Span;
+use oxc::syntax::symbol::SymbolId;
+use serde::ser::{SerializeMap, SerializeStruct};
+use serde::{Serialize, Serializer};
+use starbase_utils::json;
+
+#[derive(Serialize)]
+struct SerializedEdge<'graph> {
+    from: u32,
+    to: u32,
+    #[serde(flatten)]
+    edge: &'graph ModuleGraphEdge,
+}
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "cache", derive(serde::Deserialize))]
+struct SerializedSpan {
+    start: u32,
+    end: u32,
+}
+
+impl Serialize for ModuleGraph {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Sort everything by ID so that the output is stable
+        let mut modules = self.modules.values().map(AsRef::as_ref).collect::<Vec<_>>();
+        modules.sort_by_key(|module| module.id);
+
+        let mut edges = self
+            .graph
+            .all_edges()
+            .flat_map(|(from, to, edges)| {
+                edges
+                    .iter()
+                    .map(move |edge| SerializedEdge { from, to, edge })
+            })
+            .collect::<Vec<_>>();
+        edges.sort_by_key(|edge| (edge.from, edge.to));
+
+        let mut entries = self.entries.iter().collect::<Vec<_>>();
+        entries.sort();
+
+        let mut state = serializer.serialize_struct("ModuleGraph", 3)?;
+        state.serialize_field("entries", &entries)?;
+        state.serialize_field("modules", &modules)?;
+        state.serialize_field("edges", &edges)?;
+        state.end()
+    }
+}
+
+impl ModuleGraph {
+    /// Serialize the modules and edges of the graph to a JSON document.
+    pub fn to_json(&self, pretty: bool) -> Result<String, ModuleGraphError> {
+        Ok(json::format(self, pretty)?)
+    }
+}
+
+pub(crate) fn span<S: Serializer>(span: &Span, serializer: S) -> Result<S::Ok, S::Error> {
+    SerializedSpan {
+        start: span.start,
+        end: span.end,
+    }
+    .serialize(serializer)
+}
+
+pub(crate) fn optional_span<S: Serializer>(
+    span: &Option<Span>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match span {
+        Some(inner) => self::span(inner, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub(crate) fn symbol_id<S: Serializer>(
+    symbol_id: &Option<SymbolId>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    symbol_id.map(|id| id.index()).serialize(serializer)
+}
+
+pub(crate) fn error<S: Serializer>(
+    error: &ModuleGraphError,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(error)
+}
+
+#[allow(clippy::borrowed_box)]
+pub(crate) fn source<S: Serializer>(
+    source: &Box<dyn ModuleSource>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_map(Some(3))?;
+    state.serialize_entry("kind", &source.kind())?;
+    state.serialize_entry("metadata", &source.metadata())?;
+    state.serialize_entry("size", &source.source().len())?;
+    state.end()
+}
+
+#[cfg(feature = "cache")]
+pub(crate) fn deserialize_span<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Span, D::Error> {
+    let span = <SerializedSpan as serde::Deserialize>::deserialize(deserializer)?;
+
+    Ok(Span::new(span.start, span.end))
+}
+
+#[cfg(feature = "cache")]
+pub(crate) fn deserialize_optional_span<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Span>, D::Error> {
+    let span = <Option<SerializedSpan> as serde::Deserialize>::deserialize(deserializer)?;
+
+    Ok(span.map(|span| Span::new(span.start, span.end)))
+}
+
+#[cfg(feature = "cache")]
+pub(crate) fn deserialize_symbol_id<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<SymbolId>, D::Error> {
+    let id = <Option<usize> as serde::Deserialize>::deserialize(deserializer)?;
+
+    Ok(id.map(SymbolId::new))
+}
@@ -5,6 +5,8 @@ use starbase_utils::fs;
 use std::sync::Arc;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
 pub enum TextModuleKind {
     Graphql,
     Html,
@@ -32,6 +34,11 @@ impl ModuleSource for TextModule {
         self.source.as_bytes()
     }
 
+    #[cfg(feature = "serialize")]
+    fn metadata(&self) -> starbase_utils::json::JsonValue {
+        starbase_utils::json::json!({ "textKind": self.kind })
+    }
+
     fn load(
         module: &mut Module,
         _package_json: Option<Arc<PackageJson>>,
@@ -0,0 +1,132 @@
+use clean_path::Clean;
+use std::io;
+use std::path::{Path, PathBuf};
+use typescript_tsconfig_json::TsConfigJson;
+
+#[derive(Debug, Default)]
+pub struct TsConfigPaths {
+    /// Absolute path to the `baseUrl` directory, if configured.
+    pub base_url: Option<PathBuf>,
+
+    /// Patterns from the `paths` compiler option, in declaration order.
+    pub paths: Vec<(String, Vec<String>)>,
+
+    /// Directory that `paths` targets are relative to.
+    pub paths_dir: PathBuf,
+
+    /// Absolute path to the tsconfig file that was loaded.
+    pub tsconfig_path: PathBuf,
+}
+
+impl TsConfigPaths {
+    /// Load `baseUrl` and `paths` from the tsconfig at the provided path,
+    /// while taking the `extends` chain into account. Options from later
+    /// configs in the chain override the earlier ones.
+    pub fn load<P: AsRef<Path>>(tsconfig_path: P) -> io::Result<Self> {
+        let tsconfig_path = tsconfig_path.as_ref();
+        let mut paths = Self {
+            paths_dir: tsconfig_path.parent().unwrap().to_owned(),
+            tsconfig_path: tsconfig_path.to_owned(),
+            ..Self::default()
+        };
+        let mut paths_defined_in = None;
+
+        for item in TsConfigJson::resolve_extends_chain(tsconfig_path)? {
+            let Some(options) = &item.config.compiler_options else {
+                continue;
+            };
+
+            let config_dir = item.path.parent().unwrap();
+
+            if let Some(base_url) = &options.base_url {
+                paths.base_url = Some(config_dir.join(base_url).clean());
+            }
+
+            if let Some(map) = &options.paths {
+                paths.paths = map
+                    .iter()
+                    .map(|(key, values)| (key.to_owned(), values.to_owned()))
+                    .collect();
+                paths_defined_in = Some(config_dir.to_owned());
+            }
+        }
+
+        // Paths are relative to `baseUrl` when defined,
+        // otherwise the config that declared them
+        if let Some(base_url) = &paths.base_url {
+            paths.paths_dir = base_url.to_owned();
+        } else if let Some(dir) = paths_defined_in {
+            paths.paths_dir = dir;
+        }
+
+        Ok(paths)
+    }
+
+    /// Find the closest `tsconfig.json` by traversing upwards from
+    /// the starting directory.
+    pub fn find_tsconfig<P: AsRef<Path>>(starting_dir: P) -> Option<PathBuf> {
+        let mut current_dir = Some(starting_dir.as_ref());
+
+        while let Some(dir) = current_dir {
+            let file_path = dir.join("tsconfig.json");
+
+            if file_path.exists() {
+                return Some(file_path);
+            }
+
+            current_dir = dir.parent();
+        }
+
+        None
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.base_url.is_none() && self.paths.is_empty()
+    }
+
+    /// Return a list of absolute file paths that the specifier may map to,
+    /// in the order that they should be attempted.
+    pub fn get_candidates(&self, specifier: &str) -> Vec<PathBuf> {
+        let mut candidates = vec![];
+
+        // https://www.typescriptlang.org/docs/handbook/modules/reference.html#wildcard-substitutions
+        // When multiple patterns match, the one with the longest prefix wins
+        let mut matched: Option<(usize, &Vec<String>, &str)> = None;
+
+        for (pattern, targets) in &self.paths {
+            if let Some((prefix, suffix)) = pattern.split_once('*') {
+                if specifier.len() >= prefix.len() + suffix.len()
+                    && specifier.starts_with(prefix)
+                    && specifier.ends_with(suffix)
+                    && matched.map_or(true, |m| prefix.len() > m.0)
+                {
+                    matched = Some((
+                        prefix.len(),
+                        targets,
+                        &specifier[prefix.len()..specifier.len() - suffix.len()],
+                    ));
+                }
+            } else if pattern == specifier {
+                matched = Some((usize::MAX, targets, ""));
+
+                break;
+            }
+        }
+
+        if let Some((_, targets, wildcard)) = matched {
+            for target in targets {
+                candidates.push(
+                    self.paths_dir
+                        .join(target.replacen('*', wildcard, 1))
+                        .clean(),
+                );
+            }
+        }
+
+        if let Some(base_url) = &self.base_url {
+            candidates.push(base_url.join(specifier).clean());
+        }
+
+        candidates
+    }
+}
@@ -44,6 +44,7 @@ impl ModuleSource for YamlModule {
         // The entire document itself is a default export
         export.symbols.push(ExportedSymbol {
             kind: ExportedKind::Default,
+            exported_name: None,
             symbol_id: None,
             name: AtomStr::from("default"),
         });
@@ -54,6 +55,7 @@ impl ModuleSource for YamlModule {
                 if let YamlValue::String(key) = key {
                     export.symbols.push(ExportedSymbol {
                         kind: ExportedKind::Value,
+                        exported_name: None,
                         symbol_id: None,
                         name: AtomStr::from(key.as_str()),
                     });
@@ -0,0 +1,6 @@
+import type { Value } from './types';
+import { format } from './format';
+
+const load = () => import('./lazy');
+
+export default (value: Value) => format(value) + load;
@@ -0,0 +1,3 @@
+import { format } from './format';
+
+format(1);
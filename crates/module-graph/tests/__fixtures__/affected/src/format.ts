@@ -0,0 +1,3 @@
+import { add } from './math';
+
+export const format = (value: number) => `${add(value, 0)}`;
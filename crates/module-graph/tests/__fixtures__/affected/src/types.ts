@@ -0,0 +1 @@
+export type Value = number;
@@ -0,0 +1,3 @@
+const font = new URL('./font.woff2', import.meta.url);
+
+const wasm = new URL('./mod.wasm', import.meta.url);
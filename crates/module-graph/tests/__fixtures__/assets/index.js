@@ -0,0 +1,11 @@
+import './utils.js';
+
+const logo = new URL('./logo.png', import.meta.url);
+
+const worker = new Worker(new URL('./worker.js', import.meta.url), { type: 'module' });
+
+const shared = new SharedWorker(new URL('./worker.js', import.meta.url));
+
+const remote = new URL('https://example.com/logo.png');
+
+const relative = new URL('./logo.png', document.baseURI);
@@ -0,0 +1 @@
+export function format(value) { return String(value); }
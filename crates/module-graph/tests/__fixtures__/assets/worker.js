@@ -0,0 +1,5 @@
+import { format } from './utils.js';
+
+self.onmessage = (event) => {
+  self.postMessage(format(event.data));
+};
@@ -0,0 +1,9 @@
+import data from './data.json' with { type: 'json' };
+import legacy from './data.json' assert { type: 'json' };
+import missing from './data.json';
+import styles from './styles.css' with { type: 'css' };
+import wrong from './utils.js' with { type: 'css' };
+import './other.js';
+
+const lazy = await import('./data.json', { with: { type: 'json', extra: 'value' } });
+const lazyWrong = await import('./styles.css', { assert: { type: 'json' } });
@@ -0,0 +1 @@
+import data from './data.json';
@@ -0,0 +1,3 @@
+export * from './button';
+export * from './input';
+export { Modal } from './modal';
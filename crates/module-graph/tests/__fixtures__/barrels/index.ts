@@ -0,0 +1,4 @@
+import { Button, Input } from './components';
+import * as utils from './utils';
+import { format } from './utils/format';
+import { Page } from './page';
@@ -0,0 +1,3 @@
+import { Modal } from './components';
+
+export const Page = Modal;
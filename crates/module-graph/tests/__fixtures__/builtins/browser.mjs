@@ -0,0 +1,2 @@
+import { Buffer } from 'buffer';
+import fs from 'node:fs';
@@ -0,0 +1,4 @@
+import { readFileSync } from 'fs';
+import test from 'node:test';
+
+export function helper() {}
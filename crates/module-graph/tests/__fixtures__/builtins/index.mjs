@@ -0,0 +1,6 @@
+import fs from 'node:fs';
+import { readFile } from 'fs/promises';
+import { join } from 'path';
+import { helper } from './helper.mjs';
+
+export { EventEmitter } from 'events';
@@ -0,0 +1 @@
+import unknown from 'node:unknown';
@@ -0,0 +1 @@
+export const format = (name: string, className: string) => `<b class="${className}">${name}</b>`;
@@ -0,0 +1,7 @@
+import type { Props } from './types';
+import { format } from './format';
+import styles from './styles.module.css';
+
+export function render(props: Props) {
+  return format(props.name, styles.button);
+}
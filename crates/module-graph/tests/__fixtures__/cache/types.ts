@@ -0,0 +1,3 @@
+export interface Props {
+  name: string;
+}
@@ -0,0 +1,10 @@
+import type { Page } from './types';
+import { shared } from './shared';
+
+const routes: Record<string, () => Promise<Page>> = {
+  home: () => import('./pages/home'),
+  about: () => import('./pages/about'),
+  shared: () => import('./shared'),
+};
+
+shared(routes);
@@ -0,0 +1,5 @@
+import { widget } from '../widget';
+
+const home = () => import('./home');
+
+export default [widget, home];
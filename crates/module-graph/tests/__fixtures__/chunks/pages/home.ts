@@ -0,0 +1,6 @@
+import { shared } from '../shared';
+import { widget } from '../widget';
+
+const admin = () => import('./admin');
+
+export default shared([widget, admin]);
@@ -0,0 +1 @@
+export type Page = { default: string };
@@ -0,0 +1,5 @@
+import { type C } from './c';
+
+export interface D {
+  c: C;
+}
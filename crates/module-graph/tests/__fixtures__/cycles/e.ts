@@ -0,0 +1,3 @@
+const load = () => import('./f');
+
+export default load;
@@ -0,0 +1,3 @@
+import load from './e';
+
+export const f = load;
@@ -0,0 +1,3 @@
+import { h } from './h';
+
+export const g = h;
@@ -0,0 +1,2 @@
+export const h = 'h';
+export * from './i';
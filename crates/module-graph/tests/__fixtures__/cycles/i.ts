@@ -0,0 +1 @@
+export { g } from './g';
@@ -0,0 +1,4 @@
+import './a';
+import './c';
+import './e';
+import './g';
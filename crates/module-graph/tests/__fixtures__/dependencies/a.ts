@@ -0,0 +1,5 @@
+import { b } from './b';
+import './b';
+
+export { c } from './c';
+export const a = b;
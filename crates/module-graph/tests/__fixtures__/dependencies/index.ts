@@ -0,0 +1,8 @@
+import type { Config } from './types';
+import { a } from './a';
+import { readFileSync } from 'node:fs';
+import pkg from 'pkg';
+
+const load = () => import('./lazy');
+
+export default [a, readFileSync, pkg, load] as Config;
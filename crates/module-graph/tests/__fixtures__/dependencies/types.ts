@@ -0,0 +1,3 @@
+import type { c } from './c';
+
+export type Config = (typeof c)[];
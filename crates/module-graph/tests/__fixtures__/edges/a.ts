@@ -0,0 +1,3 @@
+export type A = unknown[];
+export const a = 1;
+export default 2;
@@ -0,0 +1,9 @@
+import { a, default as main } from './a';
+import type { A } from './a';
+
+export { b as c } from './b';
+export * from './b';
+
+const lazy = () => import('./a');
+
+export const value: A = [a, main, lazy];
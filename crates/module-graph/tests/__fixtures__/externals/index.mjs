@@ -0,0 +1,4 @@
+import pkg from "pkg";
+import { util } from "pkg/util.mjs";
+import { Button } from "@app/ui";
+import { local } from "./local.mjs";
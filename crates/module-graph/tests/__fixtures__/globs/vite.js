@@ -0,0 +1,7 @@
+const pages = import.meta.glob('./pages/*.tsx');
+
+const eagerPages = import.meta.glob(['./pages/*.tsx', '!./pages/_*.tsx'], { eager: true });
+
+const setups = import.meta.glob('./setup/**/*.js', { eager: true, import: 'default' });
+
+const missing = import.meta.glob('./missing/*.js');
@@ -0,0 +1,5 @@
+const pages = require.context('./pages', false, /\.tsx$/);
+
+const setups = require.context('./setup', true, /^\.\/nested\//i, 'lazy');
+
+const everything = require.context('./setup');
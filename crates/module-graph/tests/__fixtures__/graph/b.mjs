@@ -0,0 +1,4 @@
+import { shared } from "./shared.mjs";
+import { c } from "./c.mjs";
+
+export const b = shared + c + "b";
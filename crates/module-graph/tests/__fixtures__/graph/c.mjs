@@ -0,0 +1 @@
+export { shared as c } from "./shared.mjs";
@@ -0,0 +1,5 @@
+import { a } from "./a.mjs";
+import { b } from "./b.mjs";
+import { c } from "./c.mjs";
+
+console.log(a, b, c);
@@ -0,0 +1,5 @@
+import pkg from "pkg";
+import legacy from "legacy";
+import util from "@app/util";
+
+console.log(pkg, legacy, util);
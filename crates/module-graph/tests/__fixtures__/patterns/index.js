@@ -0,0 +1,24 @@
+import './setup.js';
+
+const lang = process.env.LANG;
+const page = 'home';
+
+async function loadLocale() {
+  return import(`./locales/${lang}.js`);
+}
+
+function loadPage() {
+  return require('./pages/' + page + '.js');
+}
+
+function loadMissing() {
+  return import(`./missing/${page}.js`);
+}
+
+function loadTemplate() {
+  return import(`./setup.js`);
+}
+
+function loadAnything(name) {
+  return import(name);
+}
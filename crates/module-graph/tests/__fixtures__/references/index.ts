@@ -0,0 +1,12 @@
+/// <reference path="globals.d.ts" />
+/// <reference types="node" />
+/// <reference types="@scope/lib" />
+/// <reference types='typed' />
+/// <reference lib="es2015" />
+// <reference path="ignored.d.ts" />
+
+import { format } from './utils';
+
+/// <reference path="after.d.ts" />
+
+export const version = format(VERSION);
@@ -0,0 +1 @@
+export function format(value: string): string { return value; }
@@ -0,0 +1 @@
+export interface Config { debug: boolean }
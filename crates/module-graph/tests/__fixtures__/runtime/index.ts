@@ -0,0 +1,8 @@
+import type { Options } from './options';
+import { type Value, value } from './value';
+import './setup';
+
+export type * from './models';
+export type { Config } from './config';
+
+export const run = (options: Options) => value;
@@ -0,0 +1 @@
+export interface Model { id: string }
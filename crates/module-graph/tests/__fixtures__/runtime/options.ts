@@ -0,0 +1,3 @@
+import { defaults } from './defaults';
+
+export type Options = typeof defaults;
@@ -0,0 +1 @@
+globalThis.ready = true;
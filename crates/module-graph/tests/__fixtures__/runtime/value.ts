@@ -0,0 +1,2 @@
+export type Value = number;
+export const value: Value = 1;
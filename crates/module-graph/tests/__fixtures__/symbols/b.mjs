@@ -0,0 +1,2 @@
+export const fromStar = 1;
+export const ambiguous = 2;
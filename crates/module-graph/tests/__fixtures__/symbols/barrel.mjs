@@ -0,0 +1,8 @@
+import { local } from './c.mjs';
+
+export { a, b as renamed } from './a.mjs';
+export * as ns from './b.mjs';
+export * from './b.mjs';
+export * from './c.mjs';
+export { default } from './a.mjs';
+export { local };
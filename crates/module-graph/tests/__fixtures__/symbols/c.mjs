@@ -0,0 +1,3 @@
+export const ambiguous = 3;
+export const local = 4;
+export * from './barrel.mjs';
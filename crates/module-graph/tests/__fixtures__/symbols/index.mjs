@@ -0,0 +1,4 @@
+import { a, renamed, ns, fromStar, ambiguous, missing, local } from './barrel.mjs';
+import main from './barrel.mjs';
+import * as all from './barrel.mjs';
+import { readFile } from 'node:fs';
@@ -0,0 +1,6 @@
+import { missing } from "./missing.mjs";
+import pkg from "missing-pkg";
+import { broken } from "./broken.mjs";
+import { ok } from "./ok.mjs";
+
+export * from "./other.mjs";
@@ -0,0 +1,4 @@
+import { broken } from "./broken.mjs";
+import pkg from "missing-pkg";
+
+export const other = broken;
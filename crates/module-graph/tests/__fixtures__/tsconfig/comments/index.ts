@@ -0,0 +1,3 @@
+import { value } from "@comments/value";
+
+console.log(value);
@@ -0,0 +1,5 @@
+import { a } from "@app/a";
+import lib from "@lib";
+import helper from "utils/helper";
+
+console.log(a, lib, helper);
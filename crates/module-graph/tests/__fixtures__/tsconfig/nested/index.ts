@@ -0,0 +1,3 @@
+import { a } from "@app/a";
+
+console.log(a);
@@ -0,0 +1 @@
+export const Dead = () => null;
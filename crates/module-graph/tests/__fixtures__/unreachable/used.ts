@@ -0,0 +1,5 @@
+import logo from './logo.png';
+
+export function used() {
+  return logo;
+}
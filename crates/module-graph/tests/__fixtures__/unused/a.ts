@@ -0,0 +1,2 @@
+export const fromBarrel = 1;
+export const notFromBarrel = 2;
@@ -0,0 +1,9 @@
+import { used, Used as Alias } from './lib';
+import { fromBarrel } from './barrel';
+import * as ns from './ns';
+import type { Foo } from './types';
+import './side-effect';
+
+const load = () => import('./dynamic');
+
+export const entry = 1;
@@ -0,0 +1,4 @@
+export const used = 1;
+export const unused = 2;
+export { used as Used };
+export default function main() {}
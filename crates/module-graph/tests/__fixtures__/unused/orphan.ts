@@ -0,0 +1,3 @@
+import { unused } from './lib';
+
+console.log(unused);
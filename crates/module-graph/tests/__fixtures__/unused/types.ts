@@ -0,0 +1,2 @@
+export interface Foo {}
+export type Bar = string;
@@ -0,0 +1,9 @@
+import type { Props } from './types';
+import { render } from './render';
+import data from './data.json';
+import './styles.css';
+
+const helpers = require('./helpers');
+const load = () => import('./lazy');
+
+export default render(data, helpers, load);
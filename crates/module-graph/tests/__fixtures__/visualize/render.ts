@@ -0,0 +1,6 @@
+import type { Props } from './types';
+import pkg from 'pkg';
+
+export function render(...args: unknown[]) {
+  return pkg(args);
+}
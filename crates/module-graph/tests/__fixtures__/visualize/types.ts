@@ -0,0 +1 @@
+export interface Props { name: string }
@@ -0,0 +1,167 @@
+mod utils;
+
+use nodejs_module_graph::{AffectedModules, AffectedModulesOptions, ModuleGraph};
+use starbase_sandbox::{create_sandbox, Sandbox};
+use utils::*;
+
+fn get_affected(
+    sandbox: &Sandbox,
+    changed_files: &[&str],
+    options: AffectedModulesOptions,
+) -> (ModuleGraph, AffectedModules) {
+    let mut graph = ModuleGraph::new();
+
+    for entry in ["src/app.ts", "src/format.test.ts", "src/math.test.ts"] {
+        graph
+            .load_module_at_path(sandbox.path().join(entry), None, None, None)
+            .unwrap();
+    }
+
+    let affected = graph
+        .get_affected_modules(sandbox.path(), changed_files, &options)
+        .unwrap();
+
+    (graph, affected)
+}
+
+mod affected {
+    use super::*;
+
+    #[test]
+    fn follows_imports_in_reverse() {
+        let sandbox = create_sandbox("affected");
+        let (graph, affected) = get_affected(
+            &sandbox,
+            &["src/math.ts"],
+            AffectedModulesOptions::default(),
+        );
+
+        assert_eq!(to_file_names(&graph, &affected.changed), ["math.ts"]);
+        assert_eq!(
+            to_file_names(&graph, &affected.modules),
+            [
+                "app.ts",
+                "format.test.ts",
+                "format.ts",
+                "math.test.ts",
+                "math.ts"
+            ]
+        );
+        assert_eq!(
+            to_file_names(&graph, &affected.entries),
+            ["app.ts", "format.test.ts", "math.test.ts"]
+        );
+    }
+
+    #[test]
+    fn includes_loaded_entries_regardless_of_order() {
+        let sandbox = create_sandbox("affected");
+
+        for files in [
+            ["src/app.ts", "src/format.ts"],
+            ["src/format.ts", "src/app.ts"],
+        ] {
+            let mut graph = ModuleGraph::new();
+
+            for file in files {
+                graph
+                    .load_module_at_path(sandbox.path().join(file), None, None, None)
+                    .unwrap();
+            }
+
+            let affected = graph
+                .get_affected_modules(
+                    sandbox.path(),
+                    &["src/math.ts"],
+                    &AffectedModulesOptions::default(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                to_file_names(&graph, &affected.entries),
+                ["app.ts", "format.ts"]
+            );
+        }
+    }
+
+    #[test]
+    fn filters_modules_by_glob() {
+        let sandbox = create_sandbox("affected");
+        let (graph, affected) = get_affected(
+            &sandbox,
+            &["src/format.ts"],
+            AffectedModulesOptions::default().with_include(["**/*.test.ts"]),
+        );
+
+        assert_eq!(to_file_names(&graph, &affected.modules), ["format.test.ts"]);
+        assert_eq!(
+            to_file_names(&graph, &affected.entries),
+            ["app.ts", "format.test.ts"]
+        );
+    }
+
+    #[test]
+    fn can_ignore_type_only_imports() {
+        let sandbox = create_sandbox("affected");
+
+        let (graph, affected) = get_affected(
+            &sandbox,
+            &["src/types.ts"],
+            AffectedModulesOptions::default(),
+        );
+
+        assert_eq!(
+            to_file_names(&graph, &affected.modules),
+            ["app.ts", "types.ts"]
+        );
+
+        let (graph, affected) = get_affected(
+            &sandbox,
+            &["src/types.ts"],
+            AffectedModulesOptions::default().with_ignore_type_only(true),
+        );
+
+        assert_eq!(to_file_names(&graph, &affected.modules), ["types.ts"]);
+    }
+
+    #[test]
+    fn can_ignore_dynamic_imports() {
+        let sandbox = create_sandbox("affected");
+
+        let (graph, affected) = get_affected(
+            &sandbox,
+            &["src/lazy.ts"],
+            AffectedModulesOptions::default(),
+        );
+
+        assert_eq!(
+            to_file_names(&graph, &affected.modules),
+            ["app.ts", "lazy.ts"]
+        );
+
+        let (graph, affected) = get_affected(
+            &sandbox,
+            &["src/lazy.ts"],
+            AffectedModulesOptions::default().with_ignore_dynamic_imports(true),
+        );
+
+        assert_eq!(to_file_names(&graph, &affected.modules), ["lazy.ts"]);
+        assert!(affected.entries.is_empty());
+    }
+
+    #[test]
+    fn reports_unknown_files() {
+        let sandbox = create_sandbox("affected");
+        let (_, affected) = get_affected(
+            &sandbox,
+            &[
+                "README.md",
+                sandbox.path().join("src/math.ts").to_str().unwrap(),
+            ],
+            AffectedModulesOptions::default(),
+        );
+
+        assert_eq!(affected.changed.len(), 1);
+        assert_eq!(affected.unknown_files, [sandbox.path().join("README.md")]);
+    }
+}
@@ -0,0 +1,96 @@
+mod utils;
+
+use nodejs_module_graph::{ChunkKind, ImportKind, SourceKind};
+use starbase_sandbox::create_sandbox;
+use std::fs;
+use utils::*;
+
+mod assets {
+    use super::*;
+
+    #[test]
+    fn extracts_asset_urls_and_workers() {
+        let sandbox = create_sandbox("assets");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.js");
+
+        assert_eq!(
+            graph.modules[&id]
+                .imports
+                .iter()
+                .map(|import| (import.source_request.to_string(), import.kind))
+                .collect::<Vec<_>>(),
+            [
+                ("./utils.js".into(), ImportKind::AsyncStatic),
+                ("./logo.png".into(), ImportKind::AssetUrl),
+                ("./worker.js".into(), ImportKind::Worker),
+                ("./worker.js".into(), ImportKind::Worker),
+            ]
+        );
+    }
+
+    #[test]
+    fn links_assets_and_workers() {
+        let sandbox = create_sandbox("assets");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.js");
+        let logo_id = graph
+            .get_module_id(sandbox.path().join("logo.png"))
+            .unwrap();
+        let worker_id = graph
+            .get_module_id(sandbox.path().join("worker.js"))
+            .unwrap();
+
+        assert!(matches!(
+            graph.modules[&logo_id].source.kind(),
+            SourceKind::Image
+        ));
+        assert!(graph.graph.contains_edge(id, logo_id));
+        assert!(graph.graph.contains_edge(id, worker_id));
+        assert_eq!(graph.modules.len(), 4);
+    }
+
+    #[test]
+    fn starts_worker_chunks() {
+        let sandbox = create_sandbox("assets");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.js");
+        let chunks = graph.get_chunks(&[id]).chunks;
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].children, [1]);
+        assert_eq!(chunks[0].modules.len(), 2);
+        assert_eq!(chunks[1].kind, ChunkKind::Worker);
+        assert_eq!(chunks[1].modules.len(), 2);
+    }
+
+    #[test]
+    fn links_unknown_file_types_as_assets() {
+        let sandbox = create_sandbox("assets");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "binary.js");
+
+        for file in ["font.woff2", "mod.wasm"] {
+            let asset_id = graph.get_module_id(sandbox.path().join(file)).unwrap();
+            let asset = &graph.modules[&asset_id];
+
+            assert!(matches!(asset.source.kind(), SourceKind::Asset));
+            assert_eq!(
+                asset.source.source(),
+                fs::read(sandbox.path().join(file)).unwrap()
+            );
+            assert!(graph.graph.contains_edge(id, asset_id));
+        }
+
+        assert_eq!(graph.modules.len(), 3);
+    }
+
+    #[test]
+    fn rereads_changed_assets() {
+        let sandbox = create_sandbox("assets");
+        let (mut graph, _) = load_graph_for_file(sandbox.path(), "binary.js");
+
+        sandbox.create_file("mod.wasm", "changed");
+
+        let ids = graph.invalidate([sandbox.path().join("mod.wasm")]);
+
+        assert_eq!(graph.update().unwrap().changed, ids);
+        assert_eq!(graph.modules[&ids[0]].source.source(), b"changed");
+    }
+}
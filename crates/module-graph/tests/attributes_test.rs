@@ -0,0 +1,79 @@
+mod utils;
+
+use nodejs_module_graph::{ImportAttributeIssue, SourceKind};
+use starbase_sandbox::create_sandbox;
+use utils::*;
+
+mod attributes {
+    use super::*;
+
+    #[test]
+    fn records_static_and_dynamic_attributes() {
+        let sandbox = create_sandbox("attributes");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.mjs");
+
+        assert_eq!(
+            graph.modules[&id]
+                .imports
+                .iter()
+                .map(|import| import.attributes.as_ref().map(|attributes| (
+                    attributes.assert,
+                    attributes
+                        .entries
+                        .iter()
+                        .map(|(key, value)| format!("{key}={value}"))
+                        .collect::<Vec<_>>()
+                )))
+                .collect::<Vec<_>>(),
+            [
+                Some((false, vec!["type=json".into()])),
+                Some((true, vec!["type=json".into()])),
+                None,
+                Some((false, vec!["type=css".into()])),
+                Some((false, vec!["type=css".into()])),
+                None,
+                Some((false, vec!["type=json".into(), "extra=value".into()])),
+                Some((true, vec!["type=json".into()])),
+            ]
+        );
+    }
+
+    #[test]
+    fn flags_mismatched_attributes() {
+        let sandbox = create_sandbox("attributes");
+        let (graph, _) = load_graph_for_file(sandbox.path(), "index.mjs");
+
+        assert_eq!(
+            graph
+                .get_import_attribute_mismatches()
+                .into_iter()
+                .map(|mismatch| (
+                    mismatch.source_request.to_string(),
+                    mismatch.issue,
+                    mismatch.target_kind
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    "./data.json".into(),
+                    ImportAttributeIssue::MissingJsonType,
+                    SourceKind::Json
+                ),
+                (
+                    "./utils.js".into(),
+                    ImportAttributeIssue::TypeMismatch {
+                        attribute_type: "css".into()
+                    },
+                    SourceKind::JavaScript
+                ),
+                (
+                    "./styles.css".into(),
+                    ImportAttributeIssue::TypeMismatch {
+                        attribute_type: "json".into()
+                    },
+                    SourceKind::Css
+                ),
+            ]
+        );
+    }
+}
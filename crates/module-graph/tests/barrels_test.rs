@@ -0,0 +1,106 @@
+mod utils;
+
+use nodejs_module_graph::{ModuleGraph, ModuleId};
+use starbase_sandbox::create_sandbox;
+use utils::*;
+
+fn to_names(graph: &ModuleGraph, ids: &[ModuleId]) -> Vec<String> {
+    ids.iter()
+        .map(|id| {
+            let path = &graph.modules[id].path;
+
+            format!(
+                "{}/{}",
+                path.parent()
+                    .unwrap()
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy(),
+                path.file_name().unwrap().to_string_lossy()
+            )
+        })
+        .collect()
+}
+
+mod barrels {
+    use super::*;
+
+    #[test]
+    fn finds_barrel_files() {
+        let sandbox = create_sandbox("barrels");
+        let graph = load_graph_for_file(sandbox.path(), "index.ts").0;
+
+        let barrels = graph.get_barrel_files(2);
+        let paths = barrels
+            .iter()
+            .map(|barrel| barrel.module_id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            to_names(&graph, &paths),
+            ["components/index.ts", "utils/index.ts"]
+        );
+
+        // Only the components barrel has enough exports
+        assert_eq!(graph.get_barrel_files(3).len(), 1);
+    }
+
+    #[test]
+    fn counts_importers() {
+        let sandbox = create_sandbox("barrels");
+        let graph = load_graph_for_file(sandbox.path(), "index.ts").0;
+
+        let barrels = graph.get_barrel_files(2);
+
+        assert_eq!(barrels[0].importers.len(), 2);
+        assert_eq!(barrels[0].imports.len(), 2);
+        assert_eq!(barrels[1].importers.len(), 1);
+        assert_eq!(
+            to_names(&graph, &barrels[0].re_exported_modules),
+            [
+                "components/button.ts",
+                "components/input.ts",
+                "components/modal.ts"
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_real_source_modules() {
+        let sandbox = create_sandbox("barrels");
+        let graph = load_graph_for_file(sandbox.path(), "index.ts").0;
+
+        let barrels = graph.get_barrel_files(2);
+        let sources = barrels
+            .iter()
+            .flat_map(|barrel| &barrel.imports)
+            .map(|import| {
+                (
+                    import.source_request.to_string(),
+                    to_names(&graph, &import.source_modules),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            sources,
+            [
+                (
+                    "./components".to_owned(),
+                    vec![
+                        "components/button.ts".to_owned(),
+                        "components/input.ts".to_owned()
+                    ]
+                ),
+                (
+                    "./components".to_owned(),
+                    vec!["components/modal.ts".to_owned()]
+                ),
+                (
+                    "./utils".to_owned(),
+                    vec!["utils/format.ts".to_owned(), "utils/parse.ts".to_owned()]
+                ),
+            ]
+        );
+    }
+}
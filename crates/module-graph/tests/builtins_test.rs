@@ -0,0 +1,141 @@
+mod utils;
+
+use nodejs_module_graph::{ModuleGraph, ModuleGraphOptions, SourceKind};
+use starbase_sandbox::create_sandbox;
+use utils::*;
+
+mod builtins {
+    use super::*;
+
+    #[test]
+    fn adds_builtin_nodes() {
+        let sandbox = create_sandbox("builtins");
+
+        assert_eq!(
+            generate_paths_for_file(sandbox.path(), "index.mjs", ModuleGraphOptions::default()),
+            vec![
+                "helper.mjs",
+                "index.mjs",
+                "node:events",
+                "node:fs",
+                "node:fs/promises",
+                "node:path",
+                "node:test",
+            ]
+        );
+    }
+
+    #[test]
+    fn shares_nodes_with_and_without_prefix() {
+        let sandbox = create_sandbox("builtins");
+
+        let mut graph = ModuleGraph::new();
+        let index_id = graph
+            .load_module_at_path(sandbox.path().join("index.mjs"), None, None, None)
+            .unwrap();
+        let helper_id = graph
+            .get_module_id(sandbox.path().join("helper.mjs"))
+            .unwrap();
+
+        let fs_id = graph.get_module_id("node:fs").unwrap();
+        let fs = graph.modules.get(&fs_id).unwrap();
+
+        assert!(fs.is_builtin());
+        assert!(!fs.is_external());
+        assert!(matches!(fs.source.kind(), SourceKind::Builtin));
+        assert!(graph.graph.contains_edge(index_id, fs_id));
+        assert!(graph.graph.contains_edge(helper_id, fs_id));
+    }
+
+    #[test]
+    fn returns_builtins_used_by_module() {
+        let sandbox = create_sandbox("builtins");
+
+        let mut graph = ModuleGraph::new();
+        let index_id = graph
+            .load_module_at_path(sandbox.path().join("index.mjs"), None, None, None)
+            .unwrap();
+
+        assert_eq!(
+            graph.get_builtins_used_by(index_id),
+            vec!["events", "fs", "fs/promises", "path"]
+        );
+    }
+
+    #[test]
+    fn returns_builtins_used_by_package() {
+        let sandbox = create_sandbox("builtins");
+
+        let mut graph = ModuleGraph::new();
+        graph.load_module(sandbox.path(), "./index.mjs").unwrap();
+
+        assert_eq!(
+            graph.get_builtins_used_by_package("app"),
+            vec!["events", "fs", "fs/promises", "path", "test"]
+        );
+        assert!(graph.get_builtins_used_by_package("unknown").is_empty());
+    }
+
+    #[test]
+    fn returns_builtins_used_by_node_modules_package() {
+        let sandbox = create_sandbox("builtins");
+
+        let mut graph = ModuleGraph::new();
+        graph.load_module(sandbox.path(), "./package.mjs").unwrap();
+
+        assert_eq!(graph.get_builtins_used_by_package("lib"), vec!["fs"]);
+    }
+
+    #[test]
+    fn doesnt_treat_unknown_prefixed_names_as_builtins() {
+        let sandbox = create_sandbox("builtins");
+        let (graph, id) = load_graph_for_file_with_options(
+            sandbox.path(),
+            "unknown.mjs",
+            ModuleGraphOptions::default().with_tolerant(true),
+        );
+
+        assert!(graph.get_module_id("node:unknown").is_none());
+        assert!(graph.get_builtins_used_by(id).is_empty());
+        assert_eq!(graph.get_diagnostics().len(), 1);
+    }
+
+    #[test]
+    fn aliases_take_precedence() {
+        let sandbox = create_sandbox("builtins");
+
+        assert_eq!(
+            generate_paths_for_file(
+                sandbox.path(),
+                "index.mjs",
+                ModuleGraphOptions::default().with_alias(
+                    "path",
+                    sandbox.path().join("path-polyfill.mjs").to_string_lossy()
+                )
+            ),
+            vec![
+                "helper.mjs",
+                "index.mjs",
+                "node:events",
+                "node:fs",
+                "node:fs/promises",
+                "node:test",
+                "path-polyfill.mjs",
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_bare_names_for_browser_targets() {
+        let sandbox = create_sandbox("builtins");
+
+        assert_eq!(
+            generate_paths_for_file(sandbox.path(), "browser.mjs", ModuleGraphOptions::browser()),
+            vec!["browser.mjs", "node:fs", "node_modules/buffer/index.js"]
+        );
+        assert_eq!(
+            generate_paths_for_file(sandbox.path(), "browser.mjs", ModuleGraphOptions::node()),
+            vec!["browser.mjs", "node:buffer", "node:fs"]
+        );
+    }
+}
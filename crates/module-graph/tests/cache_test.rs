@@ -0,0 +1,199 @@
+mod utils;
+
+use nodejs_module_graph::{ModuleGraph, ModuleGraphOptions};
+use sha2::{Digest, Sha256};
+use starbase_sandbox::{create_sandbox, Sandbox};
+use starbase_utils::{fs, json};
+use std::path::{Path, PathBuf};
+use utils::*;
+
+fn load_cached_graph(sandbox: &Sandbox) -> ModuleGraph {
+    load_graph_for_file_with_options(
+        sandbox.path(),
+        "index.ts",
+        ModuleGraphOptions::default().with_cache_dir(sandbox.path().join(".cache")),
+    )
+    .0
+}
+
+fn find_entry(sandbox: &Sandbox, file: &str) -> PathBuf {
+    let path = sandbox.path().join(file);
+
+    fs::read_dir(sandbox.path().join(".cache"))
+        .unwrap()
+        .into_iter()
+        .map(|entry| entry.path())
+        .find(|entry_path| {
+            let entry: json::JsonValue = json::read_file(entry_path).unwrap();
+
+            Path::new(entry["path"].as_str().unwrap()) == path
+        })
+        .unwrap()
+}
+
+fn get_import_requests(graph: &ModuleGraph, file: &str) -> Vec<String> {
+    let module = graph
+        .modules
+        .values()
+        .find(|module| module.path.ends_with(file))
+        .unwrap();
+
+    module
+        .imports
+        .iter()
+        .map(|import| import.source_request.to_string())
+        .collect()
+}
+
+mod cache {
+    use super::*;
+
+    #[test]
+    fn writes_entries_for_parsed_modules() {
+        let sandbox = create_sandbox("cache");
+
+        load_cached_graph(&sandbox);
+
+        // Three scripts and a stylesheet
+        assert_eq!(
+            fs::read_dir(sandbox.path().join(".cache")).unwrap().len(),
+            4
+        );
+    }
+
+    #[test]
+    fn keys_entries_by_content_digest() {
+        let sandbox = create_sandbox("cache");
+
+        load_cached_graph(&sandbox);
+
+        let entry: json::JsonValue = json::read_file(find_entry(&sandbox, "index.ts")).unwrap();
+        let content_hash = entry["contentHash"].as_str().unwrap();
+
+        assert_eq!(content_hash.len(), 64);
+        assert!(content_hash.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn warm_graph_matches_cold_graph() {
+        let sandbox = create_sandbox("cache");
+
+        let cold = load_cached_graph(&sandbox);
+        let warm = load_cached_graph(&sandbox);
+
+        assert_eq!(
+            format!("{:#?}", cold.modules),
+            format!("{:#?}", warm.modules)
+        );
+        assert_eq!(cold.to_json(false).unwrap(), warm.to_json(false).unwrap());
+    }
+
+    #[test]
+    fn restores_from_entry_without_parsing() {
+        let sandbox = create_sandbox("cache");
+
+        load_cached_graph(&sandbox);
+
+        let entry_path = find_entry(&sandbox, "index.ts");
+        let mut entry: json::JsonValue = json::read_file(&entry_path).unwrap();
+        entry["imports"].as_array_mut().unwrap().truncate(1);
+        json::write_file(&entry_path, &entry, false).unwrap();
+
+        let graph = load_cached_graph(&sandbox);
+
+        assert_eq!(get_import_requests(&graph, "index.ts"), ["./types"]);
+    }
+
+    #[test]
+    fn restores_stylesheet_without_parsing() {
+        let sandbox = create_sandbox("cache");
+
+        load_cached_graph(&sandbox);
+
+        // Contents that fail to parse only load when restored from the cache
+        let css = ".base { color: red; } }";
+
+        sandbox.create_file("styles.module.css", css);
+
+        assert!(ModuleGraph::new()
+            .load_module_at_path(sandbox.path().join("styles.module.css"), None, None, None)
+            .is_err());
+
+        let entry_path = find_entry(&sandbox, "styles.module.css");
+        let mut entry: json::JsonValue = json::read_file(&entry_path).unwrap();
+        entry["contentHash"] = json::JsonValue::from(format!("{:x}", Sha256::digest(css)));
+        json::write_file(&entry_path, &entry, false).unwrap();
+
+        let graph = load_cached_graph(&sandbox);
+
+        assert_eq!(
+            graph
+                .modules
+                .values()
+                .find(|module| module.path.ends_with("styles.module.css"))
+                .unwrap()
+                .exports
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn parses_again_when_contents_change() {
+        let sandbox = create_sandbox("cache");
+
+        load_cached_graph(&sandbox);
+
+        sandbox.create_file("index.ts", "import { format } from './format';");
+
+        let graph = load_cached_graph(&sandbox);
+
+        assert_eq!(get_import_requests(&graph, "index.ts"), ["./format"]);
+    }
+
+    #[test]
+    fn parses_again_when_version_changes() {
+        let sandbox = create_sandbox("cache");
+
+        load_cached_graph(&sandbox);
+
+        let entry_path = find_entry(&sandbox, "index.ts");
+        let mut entry: json::JsonValue = json::read_file(&entry_path).unwrap();
+        entry["imports"].as_array_mut().unwrap().truncate(1);
+        entry["version"] = json::JsonValue::from("0.0.0-old");
+        json::write_file(&entry_path, &entry, false).unwrap();
+
+        let graph = load_cached_graph(&sandbox);
+
+        assert_eq!(
+            get_import_requests(&graph, "index.ts"),
+            ["./types", "./format", "./styles.module.css"]
+        );
+    }
+
+    #[test]
+    fn parses_again_when_entry_is_corrupt() {
+        let sandbox = create_sandbox("cache");
+
+        load_cached_graph(&sandbox);
+
+        fs::write_file(find_entry(&sandbox, "styles.module.css"), "{").unwrap();
+
+        let graph = load_cached_graph(&sandbox);
+
+        assert_eq!(
+            get_import_requests(&graph, "styles.module.css"),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            graph
+                .modules
+                .values()
+                .find(|module| module.path.ends_with("styles.module.css"))
+                .unwrap()
+                .exports
+                .len(),
+            2
+        );
+    }
+}
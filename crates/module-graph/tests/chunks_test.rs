@@ -0,0 +1,113 @@
+mod utils;
+
+use nodejs_module_graph::ChunkKind;
+use starbase_sandbox::create_sandbox;
+use utils::*;
+
+mod chunks {
+    use super::*;
+
+    #[test]
+    fn splits_chunks_at_dynamic_imports() {
+        let sandbox = create_sandbox("chunks");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.ts");
+        let chunks = graph.get_chunks(&[id]).chunks;
+
+        assert_eq!(
+            chunks
+                .iter()
+                .map(|chunk| (
+                    chunk.kind,
+                    to_file_names(&graph, &[chunk.root_id])[0].clone(),
+                    to_file_names(&graph, &chunk.modules),
+                    chunk.children.clone()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    ChunkKind::Entry,
+                    "index.ts".into(),
+                    vec!["index.ts".into(), "shared.ts".into()],
+                    vec![1, 2]
+                ),
+                (
+                    ChunkKind::Async,
+                    "home.ts".into(),
+                    vec!["home.ts".into(), "shared.ts".into(), "widget.ts".into()],
+                    vec![3]
+                ),
+                (
+                    ChunkKind::Async,
+                    "about.ts".into(),
+                    vec!["about.ts".into(), "widget.ts".into()],
+                    vec![1]
+                ),
+                (
+                    ChunkKind::Async,
+                    "admin.ts".into(),
+                    vec!["admin.ts".into()],
+                    vec![]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_shared_modules() {
+        let sandbox = create_sandbox("chunks");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.ts");
+        let chunk_graph = graph.get_chunks(&[id]);
+
+        let shared = chunk_graph
+            .shared_modules
+            .iter()
+            .map(|shared| {
+                (
+                    to_file_names(&graph, &[shared.module_id])[0].clone(),
+                    shared.chunks.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            shared,
+            vec![
+                ("shared.ts".into(), vec![0, 1]),
+                ("widget.ts".into(), vec![1, 2])
+            ]
+        );
+
+        let widget = graph
+            .get_module_id(sandbox.path().join("widget.ts"))
+            .unwrap();
+
+        assert_eq!(chunk_graph.get_chunks_for(widget).len(), 2);
+    }
+
+    #[test]
+    fn estimates_sizes_from_sources() {
+        let sandbox = create_sandbox("chunks");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.ts");
+        let chunk_graph = graph.get_chunks(&[id]);
+
+        for chunk in &chunk_graph.chunks {
+            let size = chunk
+                .modules
+                .iter()
+                .map(|id| graph.modules[id].source.source().len())
+                .sum::<usize>();
+
+            assert!(size > 0);
+            assert_eq!(chunk.size, size);
+        }
+
+        let widget = &chunk_graph.shared_modules[1];
+
+        assert_eq!(
+            widget.size,
+            std::fs::metadata(sandbox.path().join("widget.ts"))
+                .unwrap()
+                .len() as usize
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use nodejs_module_graph::{ModuleCycle, ModuleCycleOptions, ModuleGraph};
+use starbase_sandbox::create_sandbox;
+use std::path::Path;
+
+fn get_cycles(root: &Path, options: ModuleCycleOptions) -> Vec<Vec<String>> {
+    let mut graph = ModuleGraph::new();
+    graph
+        .load_module_at_path(root.join("index.ts"), None, None, None)
+        .unwrap();
+
+    graph.get_cycles(&options).iter().map(to_names).collect()
+}
+
+fn to_names(cycle: &ModuleCycle) -> Vec<String> {
+    cycle
+        .paths()
+        .into_iter()
+        .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+        .collect()
+}
+
+mod cycles {
+    use super::*;
+
+    #[test]
+    fn finds_all_cycles() {
+        let sandbox = create_sandbox("cycles");
+
+        assert_eq!(
+            get_cycles(sandbox.path(), ModuleCycleOptions::default()),
+            vec![
+                vec!["a.ts", "b.ts"],
+                vec!["c.ts", "d.ts"],
+                vec!["e.ts", "f.ts"],
+                vec!["g.ts", "h.ts", "i.ts"],
+            ]
+        );
+    }
+
+    #[test]
+    fn can_ignore_type_only_imports() {
+        let sandbox = create_sandbox("cycles");
+
+        assert_eq!(
+            get_cycles(
+                sandbox.path(),
+                ModuleCycleOptions::default().with_ignore_type_only(true)
+            ),
+            vec![
+                vec!["a.ts", "b.ts"],
+                vec!["e.ts", "f.ts"],
+                vec!["g.ts", "h.ts", "i.ts"],
+            ]
+        );
+    }
+
+    #[test]
+    fn can_ignore_dynamic_imports() {
+        let sandbox = create_sandbox("cycles");
+
+        assert_eq!(
+            get_cycles(
+                sandbox.path(),
+                ModuleCycleOptions::default().with_ignore_dynamic_imports(true)
+            ),
+            vec![
+                vec!["a.ts", "b.ts"],
+                vec!["c.ts", "d.ts"],
+                vec!["g.ts", "h.ts", "i.ts"],
+            ]
+        );
+    }
+
+    #[test]
+    fn includes_specifiers_and_spans() {
+        let sandbox = create_sandbox("cycles");
+
+        let mut graph = ModuleGraph::new();
+        graph
+            .load_module_at_path(sandbox.path().join("index.ts"), None, None, None)
+            .unwrap();
+
+        let cycles = graph.get_cycles(&ModuleCycleOptions::default());
+        let cycle = cycles.last().unwrap();
+
+        let edges = cycle
+            .edges
+            .iter()
+            .map(|edge| {
+                let source = std::fs::read_to_string(&edge.path).unwrap();
+
+                (
+                    edge.source_request.to_string(),
+                    source[edge.span.start as usize..edge.span.end as usize].to_owned(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            edges,
+            vec![
+                ("./h".into(), "import { h } from './h';".into()),
+                ("./i".into(), "export * from './i';".into()),
+                ("./g".into(), "export { g } from './g';".into()),
+            ]
+        );
+        assert_eq!(cycle.edges[2].target_id, cycle.edges[0].module_id);
+    }
+
+    #[test]
+    fn includes_all_modules_of_overlapping_cycles() {
+        let sandbox = create_sandbox("cycles");
+
+        let mut graph = ModuleGraph::new();
+        graph
+            .load_module_at_path(sandbox.path().join("shared/index.ts"), None, None, None)
+            .unwrap();
+
+        let cycles = graph.get_cycles(&ModuleCycleOptions::default());
+
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(to_names(&cycles[0]), vec!["a.ts", "b.ts"]);
+        assert_eq!(
+            cycles[0]
+                .modules
+                .iter()
+                .map(|id| graph.modules[id]
+                    .path
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy())
+                .collect::<Vec<_>>(),
+            vec!["a.ts", "b.ts", "c.ts"]
+        );
+    }
+
+    #[test]
+    fn ignores_acyclic_graphs() {
+        let sandbox = create_sandbox("graph");
+
+        let mut graph = ModuleGraph::new();
+        graph
+            .load_module_at_path(sandbox.path().join("index.mjs"), None, None, None)
+            .unwrap();
+
+        assert!(graph.get_cycles(&ModuleCycleOptions::default()).is_empty());
+    }
+}
@@ -0,0 +1,196 @@
+mod utils;
+
+use nodejs_module_graph::{DependencyQueryOptions, ImportKind, ModuleDependency, ModuleGraph};
+use starbase_sandbox::{create_sandbox, Sandbox};
+use utils::*;
+
+fn get_id(graph: &ModuleGraph, sandbox: &Sandbox, file: &str) -> u32 {
+    graph.get_module_id(sandbox.path().join(file)).unwrap()
+}
+
+fn to_names(results: Vec<ModuleDependency>) -> Vec<String> {
+    results
+        .into_iter()
+        .map(|result| {
+            result
+                .path
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect()
+}
+
+mod dependencies {
+    use super::*;
+
+    #[test]
+    fn returns_direct_dependencies() {
+        let sandbox = create_sandbox("dependencies");
+        let graph = load_graph_for_file(sandbox.path(), "index.ts").0;
+        let id = get_id(&graph, &sandbox, "index.ts");
+
+        assert_eq!(
+            to_names(graph.dependencies_of(id, &DependencyQueryOptions::default())),
+            ["types.ts", "a.ts", "node:fs", "index.mjs", "lazy.ts"]
+        );
+    }
+
+    #[test]
+    fn returns_transitive_dependencies() {
+        let sandbox = create_sandbox("dependencies");
+        let graph = load_graph_for_file(sandbox.path(), "index.ts").0;
+        let id = get_id(&graph, &sandbox, "index.ts");
+        let results = graph.transitive_dependencies_of(id, &DependencyQueryOptions::default());
+
+        let c = results
+            .iter()
+            .find(|result| result.path.ends_with("c.ts"))
+            .unwrap();
+
+        assert_eq!(c.depth, 2);
+        assert_eq!(
+            c.chain,
+            [
+                id,
+                get_id(&graph, &sandbox, "types.ts"),
+                get_id(&graph, &sandbox, "c.ts")
+            ]
+        );
+        assert_eq!(
+            to_names(results),
+            [
+                "types.ts",
+                "a.ts",
+                "node:fs",
+                "index.mjs",
+                "lazy.ts",
+                "c.ts",
+                "b.ts"
+            ]
+        );
+    }
+
+    #[test]
+    fn limits_depth() {
+        let sandbox = create_sandbox("dependencies");
+        let graph = load_graph_for_file(sandbox.path(), "index.ts").0;
+        let id = get_id(&graph, &sandbox, "lazy.ts");
+
+        assert_eq!(
+            to_names(
+                graph.transitive_dependencies_of(
+                    id,
+                    &DependencyQueryOptions::default().with_depth(2)
+                )
+            ),
+            ["a.ts", "b.ts", "c.ts"]
+        );
+        assert_eq!(
+            to_names(
+                graph.transitive_dependencies_of(
+                    id,
+                    &DependencyQueryOptions::default().with_depth(1)
+                )
+            ),
+            ["a.ts"]
+        );
+    }
+
+    #[test]
+    fn filters_edges() {
+        let sandbox = create_sandbox("dependencies");
+        let graph = load_graph_for_file(sandbox.path(), "index.ts").0;
+        let id = get_id(&graph, &sandbox, "index.ts");
+
+        assert_eq!(
+            to_names(
+                graph.transitive_dependencies_of(
+                    id,
+                    &DependencyQueryOptions::default()
+                        .with_ignore_dynamic_imports(true)
+                        .with_ignore_externals(true)
+                        .with_ignore_type_only(true)
+                )
+            ),
+            ["a.ts", "b.ts", "c.ts"]
+        );
+    }
+
+    #[test]
+    fn includes_every_import_record() {
+        let sandbox = create_sandbox("dependencies");
+        let graph = load_graph_for_file(sandbox.path(), "index.ts").0;
+        let id = get_id(&graph, &sandbox, "a.ts");
+        let results = graph.dependencies_of(id, &DependencyQueryOptions::default());
+
+        let b = &results[0];
+
+        assert!(b.path.ends_with("b.ts"));
+        assert_eq!(b.edges.len(), 2);
+        assert!(b
+            .edges
+            .iter()
+            .all(|edge| edge.kind == Some(ImportKind::AsyncStatic) && edge.module_id == id));
+
+        let c = &results[1];
+
+        assert!(c.path.ends_with("c.ts"));
+        assert_eq!(c.edges.len(), 1);
+        assert_eq!(c.edges[0].kind, None);
+        assert_eq!(c.edges[0].source_request.as_str(), "./c");
+    }
+}
+
+mod dependents {
+    use super::*;
+
+    #[test]
+    fn returns_direct_dependents() {
+        let sandbox = create_sandbox("dependencies");
+        let graph = load_graph_for_file(sandbox.path(), "index.ts").0;
+        let id = get_id(&graph, &sandbox, "c.ts");
+
+        assert_eq!(
+            to_names(graph.dependents_of(id, &DependencyQueryOptions::default())),
+            ["types.ts", "a.ts", "b.ts"]
+        );
+    }
+
+    #[test]
+    fn returns_transitive_dependents() {
+        let sandbox = create_sandbox("dependencies");
+        let graph = load_graph_for_file(sandbox.path(), "index.ts").0;
+        let id = get_id(&graph, &sandbox, "b.ts");
+        let results = graph.transitive_dependents_of(id, &DependencyQueryOptions::default());
+
+        let index = results
+            .iter()
+            .find(|result| result.path.ends_with("index.ts"))
+            .unwrap();
+
+        assert_eq!(index.depth, 2);
+        assert_eq!(index.edges[0].target_id, get_id(&graph, &sandbox, "a.ts"));
+        assert_eq!(to_names(results), ["a.ts", "index.ts", "lazy.ts"]);
+    }
+
+    #[test]
+    fn filters_edges() {
+        let sandbox = create_sandbox("dependencies");
+        let graph = load_graph_for_file(sandbox.path(), "index.ts").0;
+        let id = get_id(&graph, &sandbox, "c.ts");
+
+        assert_eq!(
+            to_names(
+                graph.transitive_dependents_of(
+                    id,
+                    &DependencyQueryOptions::default()
+                        .with_ignore_dynamic_imports(true)
+                        .with_ignore_type_only(true)
+                )
+            ),
+            ["a.ts", "b.ts", "index.ts", "lazy.ts"]
+        );
+    }
+}
@@ -0,0 +1,94 @@
+mod utils;
+
+use nodejs_module_graph::{ModuleGraph, ModuleGraphOptions, SourceKind};
+use starbase_sandbox::create_sandbox;
+use utils::*;
+
+mod externals {
+    use super::*;
+
+    #[test]
+    fn traverses_packages_by_default() {
+        let sandbox = create_sandbox("externals");
+
+        assert_eq!(
+            generate_paths_for_file(sandbox.path(), "index.mjs", ModuleGraphOptions::default()),
+            vec![
+                "index.mjs",
+                "local.mjs",
+                "node_modules/@app/ui/index.mjs",
+                "node_modules/dep/index.mjs",
+                "node_modules/pkg/index.mjs",
+                "node_modules/pkg/util.mjs",
+            ]
+        );
+    }
+
+    #[test]
+    fn stops_at_node_modules() {
+        let sandbox = create_sandbox("externals");
+
+        assert_eq!(
+            generate_paths_for_file(
+                sandbox.path(),
+                "index.mjs",
+                ModuleGraphOptions::default().with_external_node_modules(true)
+            ),
+            vec![
+                "index.mjs",
+                "local.mjs",
+                "node_modules/@app/ui",
+                "node_modules/pkg",
+            ]
+        );
+    }
+
+    #[test]
+    fn stops_at_matching_packages() {
+        let sandbox = create_sandbox("externals");
+
+        assert_eq!(
+            generate_paths_for_file(
+                sandbox.path(),
+                "index.mjs",
+                ModuleGraphOptions::default().with_external_packages(["@app/*", "dep"])
+            ),
+            vec![
+                "index.mjs",
+                "local.mjs",
+                "node_modules/@app/ui",
+                "node_modules/dep",
+                "node_modules/pkg/index.mjs",
+                "node_modules/pkg/util.mjs",
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_package_name_and_version() {
+        let sandbox = create_sandbox("externals");
+        let mut graph = ModuleGraph::with_options(
+            ModuleGraphOptions::default().with_external_node_modules(true),
+        );
+
+        graph
+            .load_module_at_path(sandbox.path().join("index.mjs"), None, None, None)
+            .unwrap();
+
+        let index = &graph.modules[&1];
+
+        // Subpath imports share the package node
+        assert_eq!(index.imports[0].module_id, index.imports[1].module_id);
+
+        let pkg = &graph.modules[&index.imports[0].module_id];
+
+        assert!(pkg.is_external());
+        assert!(matches!(pkg.source.kind(), SourceKind::External));
+        assert_eq!(pkg.package_name.as_deref(), Some("pkg"));
+        assert_eq!(
+            format!("{:?}", pkg.source),
+            "ExternalModule { package_name: \"pkg\", version: Some(\"1.2.3\") }"
+        );
+        assert_eq!(graph.graph.neighbors(pkg.id).count(), 0);
+    }
+}
@@ -0,0 +1,145 @@
+mod utils;
+
+use nodejs_module_graph::{ImportGlob, ImportKind, ImportedKind, ModuleGraph};
+use starbase_sandbox::create_sandbox;
+use utils::*;
+
+fn get_requests(graph: &ModuleGraph, id: u32) -> Vec<(String, ImportKind)> {
+    graph.modules[&id]
+        .imports
+        .iter()
+        .map(|import| (import.source_request.to_string(), import.kind))
+        .collect()
+}
+
+mod import_meta_glob {
+    use super::*;
+
+    #[test]
+    fn expands_into_matching_files() {
+        let sandbox = create_sandbox("globs");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "vite.js");
+
+        assert_eq!(
+            get_requests(&graph, id),
+            [
+                ("./pages/_layout.tsx".into(), ImportKind::AsyncDynamic),
+                ("./pages/about.tsx".into(), ImportKind::AsyncDynamic),
+                ("./pages/home.tsx".into(), ImportKind::AsyncDynamic),
+                ("./pages/about.tsx".into(), ImportKind::AsyncStatic),
+                ("./pages/home.tsx".into(), ImportKind::AsyncStatic),
+                ("./setup/a.js".into(), ImportKind::AsyncStatic),
+                ("./setup/nested/b.js".into(), ImportKind::AsyncStatic),
+                ("./missing/*.js".into(), ImportKind::AsyncDynamic),
+            ]
+        );
+
+        let module = &graph.modules[&id];
+
+        for import in &module.imports[0..7] {
+            assert_ne!(import.module_id, 0);
+            assert!(graph.graph.contains_edge(id, import.module_id));
+        }
+
+        assert_eq!(module.imports[7].module_id, 0);
+    }
+
+    #[test]
+    fn records_patterns_and_symbols() {
+        let sandbox = create_sandbox("globs");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "vite.js");
+        let module = &graph.modules[&id];
+
+        let ImportGlob::Patterns(patterns) = module.imports[3].glob.as_ref().unwrap() else {
+            panic!("expected patterns");
+        };
+
+        assert_eq!(patterns.len(), 2);
+        assert_eq!(patterns[1].as_str(), "!./pages/_*.tsx");
+        assert_eq!(
+            module.imports[3].pattern.as_ref().unwrap().as_str(),
+            "./pages/*.tsx"
+        );
+
+        assert!(module.imports[0].symbols.is_empty());
+        assert!(matches!(
+            module.imports[3].symbols[0].kind,
+            ImportedKind::Namespace
+        ));
+        assert!(matches!(
+            module.imports[5].symbols[0].kind,
+            ImportedKind::Default
+        ));
+    }
+}
+
+mod require_context {
+    use super::*;
+
+    #[test]
+    fn expands_into_matching_files() {
+        let sandbox = create_sandbox("globs");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "webpack.js");
+
+        assert_eq!(
+            get_requests(&graph, id),
+            [
+                ("./pages/_layout.tsx".into(), ImportKind::SyncStatic),
+                ("./pages/about.tsx".into(), ImportKind::SyncStatic),
+                ("./pages/home.tsx".into(), ImportKind::SyncStatic),
+                ("./setup/nested/b.js".into(), ImportKind::AsyncDynamic),
+                ("./setup/a.js".into(), ImportKind::SyncStatic),
+                ("./setup/nested/b.js".into(), ImportKind::SyncStatic),
+            ]
+        );
+
+        let module = &graph.modules[&id];
+
+        for import in &module.imports {
+            assert_ne!(import.module_id, 0);
+            assert!(graph.graph.contains_edge(id, import.module_id));
+        }
+
+        assert!(graph
+            .get_module_id(sandbox.path().join("pages/styles.css"))
+            .is_none());
+    }
+
+    #[test]
+    fn skips_unsupported_files() {
+        let sandbox = create_sandbox("globs");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "webpack.js");
+
+        assert!(graph.modules[&id]
+            .imports
+            .iter()
+            .all(|import| !import.source_request.ends_with(".md")));
+        assert!(graph
+            .get_module_id(sandbox.path().join("setup/README.md"))
+            .is_none());
+    }
+
+    #[test]
+    fn records_context() {
+        let sandbox = create_sandbox("globs");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "webpack.js");
+        let module = &graph.modules[&id];
+
+        let ImportGlob::Context {
+            directory,
+            recursive,
+            regex,
+        } = module.imports[3].glob.as_ref().unwrap()
+        else {
+            panic!("expected context");
+        };
+
+        assert_eq!(directory.as_str(), "./setup");
+        assert!(recursive);
+        assert_eq!(regex.as_str(), r"(?i)^\.\/nested\/");
+        assert_eq!(
+            module.imports[3].pattern.as_ref().unwrap().as_str(),
+            "./setup/**/*"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 mod utils;
 
+use nodejs_module_graph::{ModuleGraph, SourceKind};
 use starbase_sandbox::{assert_snapshot, create_sandbox};
 use utils::*;
 
@@ -214,4 +215,23 @@ mod mjs {
             "mjs/dyn-import-patterns.mjs"
         ));
     }
+
+    #[test]
+    fn reports_javascript_kind() {
+        let sandbox = create_sandbox("js");
+
+        let mut graph = ModuleGraph::new();
+
+        for file in [
+            "cjs/require.cjs",
+            "mjs/import-named.mjs",
+            "ts/import-named.ts",
+        ] {
+            let id = graph
+                .load_module_at_path(sandbox.path().join(file), None, None, None)
+                .unwrap();
+
+            assert_eq!(graph.modules[&id].source.kind(), SourceKind::JavaScript);
+        }
+    }
 }
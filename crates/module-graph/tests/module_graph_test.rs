@@ -0,0 +1,271 @@
+mod utils;
+
+use nodejs_module_graph::{AtomStr, ImportKind, ModuleGraph, ModuleGraphEdgeKind};
+use starbase_sandbox::create_sandbox;
+use utils::*;
+
+fn to_names(symbols: &[AtomStr]) -> Vec<&str> {
+    symbols.iter().map(|symbol| symbol.as_str()).collect()
+}
+
+mod module_graph {
+    use super::*;
+
+    #[test]
+    fn assigns_ids_in_import_order() {
+        let sandbox = create_sandbox("graph");
+        let mut graph = ModuleGraph::new();
+
+        graph
+            .load_module_at_path(sandbox.path().join("index.mjs"), None, None, None)
+            .unwrap();
+
+        let ids = graph
+            .modules
+            .values()
+            .map(|module| (module.id, module.path.file_name().unwrap().to_owned()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            ids,
+            vec![
+                (3, "shared.mjs".into()),
+                (2, "a.mjs".into()),
+                (5, "c.mjs".into()),
+                (4, "b.mjs".into()),
+                (1, "index.mjs".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn output_is_deterministic() {
+        let sandbox = create_sandbox("graph");
+        let expected = generate_graph_for_file(sandbox.path(), "index.mjs");
+
+        for _ in 0..10 {
+            assert_eq!(
+                generate_graph_for_file(sandbox.path(), "index.mjs"),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn reuses_modules_across_loads() {
+        let sandbox = create_sandbox("graph");
+        let mut graph = ModuleGraph::new();
+
+        let b = graph
+            .load_module_at_path(sandbox.path().join("b.mjs"), None, None, None)
+            .unwrap();
+        let index = graph
+            .load_module_at_path(sandbox.path().join("index.mjs"), None, None, None)
+            .unwrap();
+
+        assert_eq!(b, 1);
+        assert_eq!(index, 4);
+        assert_eq!(graph.modules.len(), 5);
+        assert!(graph.graph.contains_edge(index, b));
+    }
+
+    #[test]
+    fn keeps_an_edge_for_each_import() {
+        let sandbox = create_sandbox("edges");
+        let mut graph = ModuleGraph::new();
+
+        let index = graph
+            .load_module_at_path(sandbox.path().join("index.ts"), None, None, None)
+            .unwrap();
+        let a = graph.get_module_id(sandbox.path().join("a.ts")).unwrap();
+        let b = graph.get_module_id(sandbox.path().join("b.ts")).unwrap();
+
+        let edges = graph.get_edges_between(index, a);
+
+        assert_eq!(
+            edges
+                .iter()
+                .map(|edge| (edge.import_kind, edge.type_only))
+                .collect::<Vec<_>>(),
+            vec![
+                (ImportKind::AsyncStatic, false),
+                (ImportKind::AsyncStatic, true),
+                (ImportKind::AsyncDynamic, false),
+            ]
+        );
+        assert_eq!(to_names(&edges[0].symbols), ["a", "default"]);
+        assert_eq!(to_names(&edges[1].symbols), ["A"]);
+        assert!(edges[2].symbols.is_empty());
+
+        let edges = graph.get_edges_between(index, b);
+
+        assert!(edges
+            .iter()
+            .all(|edge| edge.kind == ModuleGraphEdgeKind::Export));
+        assert_eq!(to_names(&edges[0].symbols), ["b"]);
+        assert_eq!(to_names(&edges[1].symbols), ["*"]);
+
+        assert!(graph.get_edges_between(a, index).is_empty());
+    }
+}
+
+mod update {
+    use super::*;
+    use nodejs_module_graph::ModuleGraphUpdate;
+    use std::fs;
+
+    #[test]
+    fn does_nothing_if_not_invalidated() {
+        let sandbox = create_sandbox("graph");
+        let mut graph = load_graph_for_file(sandbox.path(), "index.mjs").0;
+
+        assert!(graph
+            .invalidate([sandbox.path().join("unknown.mjs")])
+            .is_empty());
+        assert_eq!(graph.update().unwrap(), ModuleGraphUpdate::default());
+    }
+
+    #[test]
+    fn adds_and_removes_edges() {
+        let sandbox = create_sandbox("graph");
+        let mut graph = load_graph_for_file(sandbox.path(), "index.mjs").0;
+
+        sandbox.create_file("d.mjs", "export const d = 'd';");
+        sandbox.create_file(
+            "b.mjs",
+            "import { d } from './d.mjs';\nexport const b = d + 'b';",
+        );
+
+        assert_eq!(graph.invalidate([sandbox.path().join("b.mjs")]), vec![4]);
+        assert_eq!(
+            graph.update().unwrap(),
+            ModuleGraphUpdate {
+                affected: vec![1, 4],
+                added: vec![6],
+                changed: vec![4],
+                removed: vec![],
+            }
+        );
+
+        assert!(!graph.graph.contains_edge(4, 3));
+        assert!(!graph.graph.contains_edge(4, 5));
+        assert!(graph.graph.contains_edge(4, 6));
+        assert_eq!(graph.modules[&4].imports[0].module_id, 6);
+        assert_eq!(graph.get_module_id(sandbox.path().join("d.mjs")), Some(6));
+    }
+
+    #[test]
+    fn drops_orphaned_modules() {
+        let sandbox = create_sandbox("graph");
+        let mut graph = load_graph_for_file(sandbox.path(), "index.mjs").0;
+
+        sandbox.create_file(
+            "index.mjs",
+            "import { a } from './a.mjs';\nimport { b } from './b.mjs';",
+        );
+        sandbox.create_file("b.mjs", "export const b = 'b';");
+
+        graph.invalidate([
+            sandbox.path().join("index.mjs"),
+            sandbox.path().join("b.mjs"),
+        ]);
+
+        assert_eq!(
+            graph.update().unwrap(),
+            ModuleGraphUpdate {
+                affected: vec![1, 4],
+                added: vec![],
+                changed: vec![1, 4],
+                removed: vec![5],
+            }
+        );
+
+        assert!(!graph.modules.contains_key(&5));
+        assert!(!graph.graph.contains_node(5));
+        assert_eq!(graph.get_module_id(sandbox.path().join("c.mjs")), None);
+        assert!(graph.modules.contains_key(&3));
+    }
+
+    #[test]
+    fn keeps_loaded_dependencies_as_entries() {
+        let sandbox = create_sandbox("graph");
+        let mut graph = load_graph_for_file(sandbox.path(), "index.mjs").0;
+
+        graph
+            .load_module_at_path(sandbox.path().join("b.mjs"), None, None, None)
+            .unwrap();
+
+        sandbox.create_file("index.mjs", "import { a } from './a.mjs';");
+
+        graph.invalidate([sandbox.path().join("index.mjs")]);
+
+        assert_eq!(graph.update().unwrap().removed, Vec::<u32>::new());
+        assert!(graph.modules.contains_key(&4));
+    }
+
+    #[test]
+    fn removes_deleted_modules() {
+        let sandbox = create_sandbox("graph");
+        let mut graph = load_graph_for_file(sandbox.path(), "index.mjs").0;
+
+        fs::remove_file(sandbox.path().join("a.mjs")).unwrap();
+        sandbox.create_file(
+            "index.mjs",
+            "import { b } from './b.mjs';\nimport { c } from './c.mjs';",
+        );
+
+        graph.invalidate([sandbox.path().join("a.mjs")]);
+
+        assert_eq!(
+            graph.update().unwrap(),
+            ModuleGraphUpdate {
+                affected: vec![1],
+                added: vec![],
+                changed: vec![1],
+                removed: vec![2],
+            }
+        );
+
+        assert_eq!(graph.modules.len(), 4);
+    }
+
+    #[test]
+    fn keeps_graph_intact_when_update_fails() {
+        let sandbox = create_sandbox("graph");
+        let mut graph = load_graph_for_file(sandbox.path(), "index.mjs").0;
+
+        sandbox.create_file("b.mjs", "export const b = ;");
+
+        graph.invalidate([sandbox.path().join("b.mjs")]);
+
+        assert!(graph.update().is_err());
+        assert!(graph.graph.contains_edge(4, 3));
+        assert!(graph.graph.contains_edge(4, 5));
+        assert_eq!(graph.modules[&4].imports[0].module_id, 3);
+
+        // Still invalidated, so the next update picks up the fix
+        sandbox.create_file("b.mjs", "export const b = 'b';");
+
+        assert_eq!(
+            graph.update().unwrap(),
+            ModuleGraphUpdate {
+                affected: vec![1, 4],
+                added: vec![],
+                changed: vec![4],
+                removed: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn reports_transitive_dependents() {
+        let sandbox = create_sandbox("graph");
+        let mut graph = load_graph_for_file(sandbox.path(), "index.mjs").0;
+
+        sandbox.create_file("shared.mjs", "export const shared = 'changed';");
+
+        graph.invalidate([sandbox.path().join("shared.mjs")]);
+
+        assert_eq!(graph.update().unwrap().affected, vec![1, 2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,98 @@
+mod utils;
+
+use nodejs_module_graph::ModuleGraphOptions;
+use starbase_sandbox::create_sandbox;
+use utils::*;
+
+mod options {
+    use super::*;
+
+    #[test]
+    fn resolves_browser_targets() {
+        let sandbox = create_sandbox("options");
+
+        assert_eq!(
+            generate_paths_for_file(
+                sandbox.path(),
+                "index.mjs",
+                ModuleGraphOptions::browser()
+                    .with_alias("@app", sandbox.path().join("src").to_string_lossy())
+            ),
+            vec![
+                "index.mjs",
+                "node_modules/legacy/browser.js",
+                "node_modules/pkg/browser.mjs",
+                "src/util.mjs"
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_node_targets() {
+        let sandbox = create_sandbox("options");
+
+        assert_eq!(
+            generate_paths_for_file(
+                sandbox.path(),
+                "index.mjs",
+                ModuleGraphOptions::node()
+                    .with_alias("@app", sandbox.path().join("src").to_string_lossy())
+            ),
+            vec![
+                "index.mjs",
+                "node_modules/legacy/index.js",
+                "node_modules/pkg/node.mjs",
+                "src/util.mjs"
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_react_native_targets() {
+        let sandbox = create_sandbox("options");
+
+        assert_eq!(
+            generate_paths_for_file(
+                sandbox.path(),
+                "index.mjs",
+                ModuleGraphOptions::react_native()
+                    .with_alias("@app", sandbox.path().join("src").to_string_lossy())
+            ),
+            vec![
+                "index.mjs",
+                "node_modules/legacy/browser.js",
+                "node_modules/pkg/native.mjs",
+                "src/util.mjs"
+            ]
+        );
+    }
+
+    #[test]
+    fn supports_custom_conditions() {
+        let sandbox = create_sandbox("options");
+
+        assert_eq!(
+            generate_paths_for_file(
+                sandbox.path(),
+                "index.mjs",
+                ModuleGraphOptions::default()
+                    .with_condition_names(["default"])
+                    .with_alias("@app", sandbox.path().join("src").to_string_lossy())
+            ),
+            vec![
+                "index.mjs",
+                "node_modules/legacy/index.js",
+                "node_modules/pkg/default.mjs",
+                "src/util.mjs"
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ResolveFailed")]
+    fn errors_without_alias() {
+        let sandbox = create_sandbox("options");
+
+        generate_paths_for_file(sandbox.path(), "index.mjs", ModuleGraphOptions::default());
+    }
+}
@@ -0,0 +1,88 @@
+mod utils;
+
+use nodejs_module_graph::{ImportKind, ModuleGraph, ModuleGraphOptions};
+use starbase_sandbox::create_sandbox;
+use utils::*;
+
+fn get_requests(graph: &ModuleGraph, id: u32) -> Vec<(String, Option<String>)> {
+    graph.modules[&id]
+        .imports
+        .iter()
+        .map(|import| {
+            (
+                import.source_request.to_string(),
+                import.pattern.as_ref().map(|pattern| pattern.to_string()),
+            )
+        })
+        .collect()
+}
+
+mod patterns {
+    use super::*;
+
+    #[test]
+    fn records_non_literal_specifiers_as_patterns() {
+        let sandbox = create_sandbox("patterns");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.js");
+
+        assert_eq!(
+            get_requests(&graph, id),
+            [
+                ("./setup.js".into(), None),
+                ("./locales/*.js".into(), Some("./locales/*.js".into())),
+                ("./pages/*.js".into(), Some("./pages/*.js".into())),
+                ("./missing/*.js".into(), Some("./missing/*.js".into())),
+                ("./setup.js".into(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn doesnt_link_unexpanded_patterns() {
+        let sandbox = create_sandbox("patterns");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.js");
+        let module = &graph.modules[&id];
+
+        assert_eq!(graph.modules.len(), 2);
+        assert_eq!(module.imports[1].module_id, 0);
+        assert_eq!(module.imports[2].kind, ImportKind::SyncStatic);
+        assert_eq!(module.imports[2].module_id, 0);
+    }
+
+    #[test]
+    fn expands_patterns_into_matching_files() {
+        let sandbox = create_sandbox("patterns");
+        let (graph, id) = load_graph_for_file_with_options(
+            sandbox.path(),
+            "index.js",
+            ModuleGraphOptions::default().with_expand_import_patterns(true),
+        );
+
+        assert_eq!(
+            get_requests(&graph, id),
+            [
+                ("./setup.js".into(), None),
+                ("./locales/en.js".into(), Some("./locales/*.js".into())),
+                ("./locales/fr.js".into(), Some("./locales/*.js".into())),
+                ("./pages/about.js".into(), Some("./pages/*.js".into())),
+                ("./pages/home.js".into(), Some("./pages/*.js".into())),
+                ("./missing/*.js".into(), Some("./missing/*.js".into())),
+                ("./setup.js".into(), None),
+            ]
+        );
+
+        let module = &graph.modules[&id];
+
+        for import in &module.imports[1..5] {
+            assert_ne!(import.module_id, 0);
+            assert!(graph.graph.contains_edge(id, import.module_id));
+        }
+
+        assert_eq!(module.imports[1].kind, ImportKind::AsyncDynamic);
+        assert_eq!(module.imports[3].kind, ImportKind::SyncStatic);
+        assert_eq!(module.imports[5].module_id, 0);
+        assert!(graph
+            .get_module_id(sandbox.path().join("locales/nested/de.js"))
+            .is_none());
+    }
+}
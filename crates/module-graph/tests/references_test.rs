@@ -0,0 +1,96 @@
+mod utils;
+
+use nodejs_module_graph::ImportKind;
+use starbase_sandbox::create_sandbox;
+use utils::*;
+
+mod references {
+    use super::*;
+
+    #[test]
+    fn extracts_directives_before_first_statement() {
+        let sandbox = create_sandbox("references");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.ts");
+        let module = &graph.modules[&id];
+
+        assert_eq!(
+            module
+                .imports
+                .iter()
+                .map(|import| (
+                    import.source_request.to_string(),
+                    import.kind,
+                    import.type_only
+                ))
+                .collect::<Vec<_>>(),
+            [
+                ("./globals.d.ts".into(), ImportKind::ReferencePath, true),
+                ("node".into(), ImportKind::ReferenceTypes, true),
+                ("@scope/lib".into(), ImportKind::ReferenceTypes, true),
+                ("typed".into(), ImportKind::ReferenceTypes, true),
+                ("es2015".into(), ImportKind::ReferenceLib, true),
+                ("./utils".into(), ImportKind::AsyncStatic, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_paths_and_types() {
+        let sandbox = create_sandbox("references");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.ts");
+        let module = &graph.modules[&id];
+
+        for (index, path) in [
+            "globals.d.ts",
+            "node_modules/@types/node/index.d.ts",
+            "node_modules/@types/scope__lib/index.d.ts",
+            "node_modules/typed/dist/typed.d.ts",
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            assert_eq!(
+                graph.get_module_id(sandbox.path().join(path)),
+                Some(module.imports[index].module_id)
+            );
+            assert!(graph
+                .graph
+                .contains_edge(id, module.imports[index].module_id));
+        }
+    }
+
+    #[test]
+    fn doesnt_link_libs() {
+        let sandbox = create_sandbox("references");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.ts");
+
+        assert_eq!(graph.modules[&id].imports[4].module_id, 0);
+        assert_eq!(graph.modules.len(), 6);
+    }
+
+    #[test]
+    fn resolves_types_condition_of_subpaths() {
+        let sandbox = create_sandbox("references");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "vite.ts");
+
+        assert_eq!(
+            graph.get_module_id(sandbox.path().join("node_modules/vite/client.d.ts")),
+            Some(graph.modules[&id].imports[0].module_id)
+        );
+    }
+
+    #[test]
+    fn reports_unresolved_types_as_diagnostics() {
+        let sandbox = create_sandbox("references");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "vite.ts");
+        let diagnostics = graph.get_diagnostics();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].0, id);
+        assert_eq!(diagnostics[0].1.source_request.as_str(), "missing");
+        assert_eq!(
+            diagnostics[0].1.module_id,
+            graph.modules[&id].imports[1].module_id
+        );
+    }
+}
@@ -0,0 +1,61 @@
+mod utils;
+
+use petgraph::visit::IntoNeighbors;
+use starbase_sandbox::create_sandbox;
+use utils::*;
+
+mod runtime {
+    use super::*;
+
+    #[test]
+    fn excludes_type_only_edges_from_view() {
+        let sandbox = create_sandbox("runtime");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.ts");
+
+        assert_eq!(
+            to_file_names(
+                &graph,
+                &graph.runtime_graph().neighbors(id).collect::<Vec<_>>()
+            ),
+            ["setup.ts", "value.ts"]
+        );
+        assert_eq!(graph.graph.neighbors(id).count(), 5);
+    }
+
+    #[test]
+    fn checks_for_runtime_edges() {
+        let sandbox = create_sandbox("runtime");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.ts");
+        let value = graph
+            .get_module_id(sandbox.path().join("value.ts"))
+            .unwrap();
+        let options = graph
+            .get_module_id(sandbox.path().join("options.ts"))
+            .unwrap();
+
+        assert!(graph.has_runtime_edge(id, value));
+        assert!(!graph.has_runtime_edge(id, options));
+    }
+
+    #[test]
+    fn finds_runtime_modules() {
+        let sandbox = create_sandbox("runtime");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.ts");
+
+        assert_eq!(
+            to_file_names(&graph, &graph.get_runtime_modules(&[id])),
+            ["index.ts", "setup.ts", "value.ts"]
+        );
+    }
+
+    #[test]
+    fn finds_type_only_modules() {
+        let sandbox = create_sandbox("runtime");
+        let (graph, id) = load_graph_for_file(sandbox.path(), "index.ts");
+
+        assert_eq!(
+            to_file_names(&graph, &graph.get_type_only_modules(&[id])),
+            ["config.ts", "defaults.ts", "models.ts", "options.ts"]
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use nodejs_module_graph::ModuleGraph;
+use starbase_sandbox::{assert_snapshot, create_sandbox};
+
+mod serialize {
+    use super::*;
+
+    #[test]
+    fn serializes_graph_to_json() {
+        let sandbox = create_sandbox("graph");
+
+        let mut graph = ModuleGraph::new();
+        graph
+            .load_module_at_path(sandbox.path().join("index.mjs"), None, None, None)
+            .unwrap();
+
+        let root = sandbox.path().to_string_lossy().replace('\\', "/");
+
+        assert_snapshot!(graph
+            .to_json(true)
+            .unwrap()
+            .replace("\\\\", "/")
+            .replace(root.as_str(), "/root")
+            .replace("/private", ""));
+    }
+
+    #[test]
+    fn output_is_stable() {
+        let sandbox = create_sandbox("graph");
+        let mut outputs = vec![];
+
+        for _ in 0..5 {
+            let mut graph = ModuleGraph::new();
+            graph
+                .load_module_at_path(sandbox.path().join("index.mjs"), None, None, None)
+                .unwrap();
+
+            outputs.push(graph.to_json(false).unwrap());
+        }
+
+        outputs.dedup();
+
+        assert_eq!(outputs.len(), 1);
+    }
+}
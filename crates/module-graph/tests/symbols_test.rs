@@ -0,0 +1,128 @@
+use nodejs_module_graph::{ModuleGraph, SymbolResolution};
+use starbase_sandbox::create_sandbox;
+
+fn describe(graph: &ModuleGraph, resolution: &SymbolResolution) -> String {
+    let file_name = |id| {
+        graph.modules[&id]
+            .path
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string()
+    };
+
+    match resolution {
+        SymbolResolution::Resolved {
+            module_id, name, ..
+        } => format!("{}#{name}", file_name(*module_id)),
+        SymbolResolution::Namespace { module_id } => format!("{}#*", file_name(*module_id)),
+        SymbolResolution::Ambiguous { module_ids } => format!(
+            "ambiguous({})",
+            module_ids
+                .iter()
+                .map(|id| file_name(*id))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        SymbolResolution::Missing => "missing".into(),
+    }
+}
+
+mod symbols {
+    use super::*;
+
+    #[test]
+    fn links_imports_through_re_exports() {
+        let sandbox = create_sandbox("symbols");
+
+        let mut graph = ModuleGraph::new();
+        let index_id = graph
+            .load_module_at_path(sandbox.path().join("index.mjs"), None, None, None)
+            .unwrap();
+
+        let linked = graph
+            .link_symbols_for(index_id)
+            .iter()
+            .map(|symbol| {
+                (
+                    symbol.name.to_string(),
+                    symbol.imported_name.to_string(),
+                    describe(&graph, &symbol.resolution),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            linked,
+            [
+                ("a", "a", "a.mjs#a"),
+                ("renamed", "renamed", "a.mjs#b"),
+                ("ns", "ns", "b.mjs#*"),
+                ("fromStar", "fromStar", "b.mjs#fromStar"),
+                ("ambiguous", "ambiguous", "ambiguous(c.mjs, b.mjs)"),
+                ("missing", "missing", "missing"),
+                ("local", "local", "c.mjs#local"),
+                ("main", "default", "a.mjs#main"),
+                ("all", "*", "barrel.mjs#*"),
+                ("readFile", "readFile", "node:fs#readFile"),
+            ]
+            .into_iter()
+            .map(|(a, b, c)| (a.to_owned(), b.to_owned(), c.to_owned()))
+            .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn resolves_exported_symbols() {
+        let sandbox = create_sandbox("symbols");
+
+        let mut graph = ModuleGraph::new();
+        graph
+            .load_module_at_path(sandbox.path().join("index.mjs"), None, None, None)
+            .unwrap();
+
+        let barrel_id = graph
+            .get_module_id(sandbox.path().join("barrel.mjs"))
+            .unwrap();
+        let c_id = graph.get_module_id(sandbox.path().join("c.mjs")).unwrap();
+
+        assert_eq!(
+            describe(&graph, &graph.resolve_exported_symbol(barrel_id, "renamed")),
+            "a.mjs#b"
+        );
+
+        // Star exports never include the default export
+        assert_eq!(
+            graph.resolve_exported_symbol(c_id, "default"),
+            SymbolResolution::Missing
+        );
+
+        // Circular star exports do not resolve
+        assert_eq!(
+            describe(&graph, &graph.resolve_exported_symbol(c_id, "fromStar")),
+            "b.mjs#fromStar"
+        );
+        assert_eq!(
+            graph.resolve_exported_symbol(c_id, "unknown"),
+            SymbolResolution::Missing
+        );
+    }
+
+    #[test]
+    fn links_all_modules() {
+        let sandbox = create_sandbox("symbols");
+
+        let mut graph = ModuleGraph::new();
+        graph
+            .load_module_at_path(sandbox.path().join("index.mjs"), None, None, None)
+            .unwrap();
+
+        let linked = graph.link_symbols();
+
+        assert_eq!(linked.len(), 11);
+        assert_eq!(
+            describe(&graph, &linked.last().unwrap().resolution),
+            "c.mjs#local"
+        );
+    }
+}
@@ -0,0 +1,112 @@
+mod utils;
+
+use nodejs_module_graph::{ModuleGraph, ModuleGraphError, ModuleGraphOptions, SourceKind};
+use starbase_sandbox::create_sandbox;
+use utils::*;
+
+mod tolerant {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "ResolveFailed")]
+    fn errors_when_disabled() {
+        let sandbox = create_sandbox("tolerant");
+        let mut graph = ModuleGraph::new();
+
+        graph
+            .load_module_at_path(sandbox.path().join("index.mjs"), None, None, None)
+            .unwrap();
+    }
+
+    #[test]
+    fn loads_the_rest_of_the_graph() {
+        let sandbox = create_sandbox("tolerant");
+        let (graph, _) = load_graph_for_file_with_options(
+            sandbox.path(),
+            "index.mjs",
+            ModuleGraphOptions::default().with_tolerant(true),
+        );
+
+        assert!(graph.get_module_id(sandbox.path().join("ok.mjs")).is_some());
+        assert!(graph
+            .get_module_id(sandbox.path().join("other.mjs"))
+            .is_some());
+        assert_eq!(graph.modules.len(), 6);
+    }
+
+    #[test]
+    fn attaches_diagnostics_to_importers() {
+        let sandbox = create_sandbox("tolerant");
+        let (graph, _) = load_graph_for_file_with_options(
+            sandbox.path(),
+            "index.mjs",
+            ModuleGraphOptions::default().with_tolerant(true),
+        );
+
+        let index = &graph.modules[&1];
+        let requests = index
+            .diagnostics
+            .iter()
+            .map(|diag| diag.source_request.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            requests,
+            vec!["./missing.mjs", "missing-pkg", "./broken.mjs"]
+        );
+        assert_eq!(index.diagnostics[0].span.start, 0);
+        assert!(matches!(
+            &*index.diagnostics[0].error,
+            ModuleGraphError::ResolveFailed { .. }
+        ));
+        assert!(matches!(
+            &*index.diagnostics[2].error,
+            ModuleGraphError::Js(_)
+        ));
+
+        let other_id = graph
+            .get_module_id(sandbox.path().join("other.mjs"))
+            .unwrap();
+
+        assert_eq!(graph.modules[&other_id].diagnostics.len(), 2);
+        assert_eq!(graph.get_diagnostics().len(), 5);
+    }
+
+    #[test]
+    fn creates_placeholders_for_failures() {
+        let sandbox = create_sandbox("tolerant");
+        let (graph, _) = load_graph_for_file_with_options(
+            sandbox.path(),
+            "index.mjs",
+            ModuleGraphOptions::default().with_tolerant(true),
+        );
+        let index = &graph.modules[&1];
+
+        // Unresolved targets are shared across importers
+        let missing_pkg = index.imports[1].module_id;
+        let other_id = graph
+            .get_module_id(sandbox.path().join("other.mjs"))
+            .unwrap();
+
+        assert_eq!(graph.modules[&other_id].imports[1].module_id, missing_pkg);
+        assert_eq!(
+            graph.modules[&missing_pkg].path.to_str(),
+            Some("missing-pkg")
+        );
+        assert!(matches!(
+            graph.modules[&missing_pkg].source.kind(),
+            SourceKind::Unknown
+        ));
+        assert!(graph.graph.contains_edge(1, missing_pkg));
+
+        // Failed modules keep their path
+        let broken = index.imports[2].module_id;
+
+        assert_eq!(
+            graph.get_module_id(sandbox.path().join("broken.mjs")),
+            Some(broken)
+        );
+        assert!(graph.get_load_error(broken).is_some());
+        assert!(graph.get_load_error(1).is_none());
+    }
+}
@@ -0,0 +1,108 @@
+mod utils;
+
+use nodejs_module_graph::{ModuleGraphError, ModuleGraphOptions, TsConfigPaths};
+use starbase_sandbox::create_sandbox;
+use std::path::PathBuf;
+use utils::*;
+
+mod tsconfig_paths {
+    use super::*;
+
+    #[test]
+    fn resolves_paths_and_base_url_through_extends() {
+        let sandbox = create_sandbox("tsconfig");
+
+        assert_eq!(
+            generate_paths_for_file(sandbox.path(), "index.ts", ModuleGraphOptions::default()),
+            vec![
+                "index.ts",
+                "src/a.ts",
+                "src/lib/index.ts",
+                "utils/helper.ts"
+            ]
+        );
+    }
+
+    #[test]
+    fn uses_closest_tsconfig_to_importer() {
+        let sandbox = create_sandbox("tsconfig");
+
+        assert_eq!(
+            generate_paths_for_file(
+                sandbox.path(),
+                "nested/index.ts",
+                ModuleGraphOptions::default()
+            ),
+            vec!["nested/index.ts", "nested/override/a.ts"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ResolveFailed")]
+    fn can_disable() {
+        let sandbox = create_sandbox("tsconfig");
+
+        generate_paths_for_file(
+            sandbox.path(),
+            "index.ts",
+            ModuleGraphOptions::default().with_tsconfig_paths(false),
+        );
+    }
+
+    #[test]
+    fn supports_comments_and_trailing_commas() {
+        let sandbox = create_sandbox("tsconfig");
+
+        assert_eq!(
+            generate_paths_for_file(
+                sandbox.path(),
+                "comments/index.ts",
+                ModuleGraphOptions::default()
+            ),
+            vec!["comments/index.ts", "comments/src/value.ts"]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_tsconfig_as_diagnostic() {
+        let sandbox = create_sandbox("tsconfig");
+        let (graph, _) = load_graph_for_file(sandbox.path(), "broken/index.ts");
+        let diagnostics = graph.get_diagnostics();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].1.source_request.as_str(), "lib");
+        assert!(matches!(
+            *diagnostics[0].1.error,
+            ModuleGraphError::TsConfigLoadFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn prefers_longest_prefix_match() {
+        let paths = TsConfigPaths {
+            paths: vec![
+                ("*".into(), vec!["types/*".into()]),
+                ("@app/*".into(), vec!["src/*".into(), "lib/*".into()]),
+                ("@app/ui/*".into(), vec!["ui/*".into()]),
+            ],
+            paths_dir: PathBuf::from("/root"),
+            ..TsConfigPaths::default()
+        };
+
+        assert_eq!(
+            paths.get_candidates("@app/utils"),
+            vec![
+                PathBuf::from("/root/src/utils"),
+                PathBuf::from("/root/lib/utils")
+            ]
+        );
+        assert_eq!(
+            paths.get_candidates("@app/ui/button"),
+            vec![PathBuf::from("/root/ui/button")]
+        );
+        assert_eq!(
+            paths.get_candidates("react"),
+            vec![PathBuf::from("/root/types/react")]
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use nodejs_module_graph::{ModuleGraph, UnreachableFilesOptions};
+use starbase_sandbox::create_sandbox;
+use std::path::Path;
+
+fn get_unreachable_files(root: &Path, options: UnreachableFilesOptions) -> Vec<String> {
+    let mut graph = ModuleGraph::new();
+    let index_id = graph
+        .load_module_at_path(root.join("index.ts"), None, None, None)
+        .unwrap();
+
+    graph
+        .get_unreachable_files(root, &[index_id], &options)
+        .unwrap()
+        .into_iter()
+        .map(|path| {
+            path.strip_prefix(root)
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+        .collect()
+}
+
+mod unreachable {
+    use super::*;
+
+    #[test]
+    fn finds_unreachable_source_files() {
+        let sandbox = create_sandbox("unreachable");
+
+        assert_eq!(
+            get_unreachable_files(sandbox.path(), UnreachableFilesOptions::default()),
+            [
+                "data.json",
+                "dead.ts",
+                "nested/dead.tsx",
+                "styles/dead.css",
+                "used.test.ts"
+            ]
+        );
+    }
+
+    #[test]
+    fn supports_include_and_exclude() {
+        let sandbox = create_sandbox("unreachable");
+
+        assert_eq!(
+            get_unreachable_files(
+                sandbox.path(),
+                UnreachableFilesOptions::default()
+                    .with_include(["**/*.{ts,tsx}"])
+                    .with_exclude(["**/*.test.ts"])
+            ),
+            ["dead.ts", "nested/dead.tsx"]
+        );
+    }
+
+    #[test]
+    fn returns_nothing_when_all_reachable() {
+        let sandbox = create_sandbox("unreachable");
+        let root = sandbox.path();
+
+        let mut graph = ModuleGraph::new();
+        let entries = ["index.ts", "dead.ts", "used.test.ts", "nested/dead.tsx"]
+            .into_iter()
+            .map(|file| {
+                graph
+                    .load_module_at_path(root.join(file), None, None, None)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            graph
+                .get_unreachable_files(
+                    root,
+                    &entries,
+                    &UnreachableFilesOptions::default().with_include(["**/*.{ts,tsx}"])
+                )
+                .unwrap(),
+            Vec::<std::path::PathBuf>::new()
+        );
+    }
+}
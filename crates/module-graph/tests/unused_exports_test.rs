@@ -0,0 +1,95 @@
+use nodejs_module_graph::ModuleGraph;
+use starbase_sandbox::create_sandbox;
+
+mod unused_exports {
+    use super::*;
+
+    #[test]
+    fn finds_unused_exports() {
+        let sandbox = create_sandbox("unused");
+
+        let mut graph = ModuleGraph::new();
+        let index_id = graph
+            .load_module_at_path(sandbox.path().join("index.ts"), None, None, None)
+            .unwrap();
+
+        let unused = graph
+            .get_unused_exports(&[index_id])
+            .iter()
+            .map(|export| {
+                (
+                    export
+                        .path
+                        .file_name()
+                        .unwrap()
+                        .to_string_lossy()
+                        .to_string(),
+                    export.name.to_string(),
+                    export.type_only,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            unused,
+            [
+                ("lib.ts", "unused", false),
+                ("lib.ts", "default", false),
+                ("barrel.ts", "b", false),
+                ("a.ts", "notFromBarrel", false),
+                ("b.ts", "b", false),
+                ("b.ts", "c", false),
+                ("types.ts", "Bar", true),
+                ("side-effect.ts", "effect", false),
+            ]
+            .into_iter()
+            .map(|(a, b, c)| (a.to_owned(), b.to_owned(), c))
+            .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn treats_all_entry_exports_as_used() {
+        let sandbox = create_sandbox("unused");
+
+        let mut graph = ModuleGraph::new();
+        let index_id = graph
+            .load_module_at_path(sandbox.path().join("index.ts"), None, None, None)
+            .unwrap();
+        let lib_id = graph.get_module_id(sandbox.path().join("lib.ts")).unwrap();
+        let barrel_id = graph
+            .get_module_id(sandbox.path().join("barrel.ts"))
+            .unwrap();
+
+        let unused = graph
+            .get_unused_exports(&[index_id, lib_id, barrel_id])
+            .iter()
+            .map(|export| export.name.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(unused, ["c", "Bar", "effect"]);
+    }
+
+    #[test]
+    fn ignores_imports_of_unreachable_modules() {
+        let sandbox = create_sandbox("unused");
+
+        let mut graph = ModuleGraph::new();
+        let index_id = graph
+            .load_module_at_path(sandbox.path().join("index.ts"), None, None, None)
+            .unwrap();
+        let orphan_id = graph
+            .load_module_at_path(sandbox.path().join("orphan.ts"), None, None, None)
+            .unwrap();
+
+        let is_lib_unused = |entries: &[u32]| {
+            graph
+                .get_unused_exports(entries)
+                .iter()
+                .any(|export| export.path.ends_with("lib.ts") && export.name.as_str() == "unused")
+        };
+
+        assert!(is_lib_unused(&[index_id]));
+        assert!(!is_lib_unused(&[index_id, orphan_id]));
+    }
+}
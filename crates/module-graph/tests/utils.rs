@@ -1,8 +1,45 @@
 #![allow(dead_code)]
 
-use nodejs_module_graph::{Module, ModuleGraph};
+use nodejs_module_graph::{Module, ModuleGraph, ModuleGraphOptions, ModuleId};
 use std::path::Path;
 
+pub fn load_graph_for_file(
+    root: impl AsRef<Path>,
+    file: impl AsRef<str>,
+) -> (ModuleGraph, ModuleId) {
+    load_graph_for_file_with_options(root, file, ModuleGraphOptions::default())
+}
+
+pub fn load_graph_for_file_with_options(
+    root: impl AsRef<Path>,
+    file: impl AsRef<str>,
+    options: ModuleGraphOptions,
+) -> (ModuleGraph, ModuleId) {
+    let mut graph = ModuleGraph::with_options(options);
+    let id = graph
+        .load_module_at_path(root.as_ref().join(file.as_ref()), None, None, None)
+        .unwrap();
+
+    (graph, id)
+}
+
+/// Return the file names of the modules, sorted.
+pub fn to_file_names(graph: &ModuleGraph, ids: &[ModuleId]) -> Vec<String> {
+    let mut names = ids
+        .iter()
+        .map(|id| {
+            graph.modules[id]
+                .path
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
 pub fn generate_module_for_file(
     root: impl AsRef<Path>,
     file: impl AsRef<str>,
@@ -32,3 +69,32 @@ pub fn generate_graph_for_file(root: impl AsRef<Path>, file: impl AsRef<str>) ->
         .replace(root.as_str(), "/root")
         .replace("/private", "")
 }
+
+pub fn generate_paths_for_file(
+    root: impl AsRef<Path>,
+    file: impl AsRef<str>,
+    options: ModuleGraphOptions,
+) -> Vec<String> {
+    let root = root.as_ref();
+
+    let mut graph = ModuleGraph::with_options(options);
+    let _ = graph
+        .load_module_at_path(root.join(file.as_ref()), None, None, None)
+        .unwrap();
+
+    let mut paths = graph
+        .modules
+        .values()
+        .map(|module| {
+            module
+                .path
+                .strip_prefix(root)
+                .unwrap_or(&module.path)
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+        .collect::<Vec<_>>();
+
+    paths.sort();
+    paths
+}
@@ -0,0 +1,57 @@
+use nodejs_module_graph::{ModuleGraph, ModuleGraphVisualizeOptions};
+use starbase_sandbox::{assert_snapshot, create_sandbox, Sandbox};
+
+fn load_graph(sandbox: &Sandbox) -> ModuleGraph {
+    let mut graph = ModuleGraph::new();
+    graph.load_module(sandbox.path(), "./index.ts").unwrap();
+    graph
+}
+
+fn all_options(sandbox: &Sandbox) -> ModuleGraphVisualizeOptions {
+    ModuleGraphVisualizeOptions::default()
+        .with_cluster_packages(true)
+        .with_collapse_externals(true)
+        .with_color_by_kind(true)
+        .with_style_edges(true)
+        .with_root(sandbox.path())
+}
+
+mod visualize {
+    use super::*;
+
+    #[test]
+    fn renders_dot() {
+        let sandbox = create_sandbox("visualize");
+        let graph = load_graph(&sandbox);
+
+        assert_snapshot!(
+            graph.to_dot(&ModuleGraphVisualizeOptions::default().with_root(sandbox.path()))
+        );
+    }
+
+    #[test]
+    fn renders_dot_with_all_options() {
+        let sandbox = create_sandbox("visualize");
+        let graph = load_graph(&sandbox);
+
+        assert_snapshot!(graph.to_dot(&all_options(&sandbox)));
+    }
+
+    #[test]
+    fn renders_mermaid() {
+        let sandbox = create_sandbox("visualize");
+        let graph = load_graph(&sandbox);
+
+        assert_snapshot!(
+            graph.to_mermaid(&ModuleGraphVisualizeOptions::default().with_root(sandbox.path()))
+        );
+    }
+
+    #[test]
+    fn renders_mermaid_with_all_options() {
+        let sandbox = create_sandbox("visualize");
+        let graph = load_graph(&sandbox);
+
+        assert_snapshot!(graph.to_mermaid(&all_options(&sandbox)));
+    }
+}
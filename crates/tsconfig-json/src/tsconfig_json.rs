@@ -102,8 +102,11 @@ fn resolve_extends_chain_deep(
     path: PathBuf,
     chain: &mut Vec<TsConfigExtendsChain>,
 ) -> io::Result<()> {
-    let config = fs::read(&path)?;
-    let config: TsConfigJson = serde_json::from_slice(&config)?;
+    // tsconfig files are JSONC, so strip comments and trailing commas
+    let mut config = fs::read_to_string(&path)?;
+    json_strip_comments::strip(&mut config)?;
+
+    let config: TsConfigJson = serde_json::from_str(&config)?;
     let mut inner_chain = vec![];
 
     if let Some(extends) = &config.extends {
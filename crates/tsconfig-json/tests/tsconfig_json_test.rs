@@ -185,6 +185,29 @@ mod extends_chain {
         );
     }
 
+    #[test]
+    fn resolves_with_comments_and_trailing_commas() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(
+            "tsconfig.json",
+            "{\n  // Comment\n  \"include\": [\"file.tsx\",],\n  /* Block */\n}",
+        );
+
+        let chain =
+            TsConfigJson::resolve_extends_chain(sandbox.path().join("tsconfig.json")).unwrap();
+
+        assert_eq!(
+            chain,
+            vec![TsConfigExtendsChain {
+                path: sandbox.path().join("tsconfig.json"),
+                config: TsConfigJson {
+                    include: Some(vec![PathOrGlob::Path("file.tsx".into())]),
+                    ..TsConfigJson::default()
+                }
+            }]
+        );
+    }
+
     #[test]
     fn resolves_multiple() {
         let sandbox = create_empty_sandbox();